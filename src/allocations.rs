@@ -0,0 +1,190 @@
+//! Counts heap allocations when the crate is built with the `count-allocations` feature,
+//! which replaces the global allocator with a counting wrapper around the system one.
+
+use std::{cell::Cell, fmt::Display};
+
+/// Whether allocations are counted at all.
+pub const ENABLED: bool = cfg!(feature = "count-allocations");
+
+/// Heap use of one phase of a day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct AllocStats {
+    /// Allocations, reallocations included.
+    pub allocations: u64,
+    /// Bytes requested by those allocations, whether they were freed again or not.
+    pub bytes: u64,
+    /// Most bytes in use at once, above what was in use when the phase started.
+    pub peak: u64,
+}
+
+impl Display for AllocStats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let plural = if self.allocations == 1 { "" } else { "s" };
+
+        write!(
+            f,
+            "{} allocation{plural}, {} bytes, peak {} bytes",
+            self.allocations, self.bytes, self.peak
+        )
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Counters {
+    allocations: u64,
+    bytes: u64,
+    /// Can go below 0 when memory allocated on another thread is freed on this one.
+    live: i64,
+    peak: i64,
+}
+
+thread_local! {
+    // Counted per thread, so that days running on other threads don't add to each other.
+    static COUNTERS: Cell<Counters> = const {
+        Cell::new(Counters {
+            allocations: 0,
+            bytes: 0,
+            live: 0,
+            peak: 0,
+        })
+    };
+}
+
+/// Runs `f` and counts the allocations it makes on the current thread. The stats are `None`
+/// if allocations aren't counted.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Option<AllocStats>) {
+    if !ENABLED {
+        return (f(), None);
+    }
+
+    let start = COUNTERS.with(|counters| {
+        let start = counters.get();
+        counters.set(Counters {
+            peak: start.live,
+            ..start
+        });
+        start
+    });
+
+    let value = f();
+
+    let end = COUNTERS.with(|counters| {
+        let end = counters.get();
+        // Keep the peak of an enclosing measurement.
+        counters.set(Counters {
+            peak: end.peak.max(start.peak),
+            ..end
+        });
+        end
+    });
+
+    let stats = AllocStats {
+        allocations: end.allocations - start.allocations,
+        bytes: end.bytes - start.bytes,
+        peak: (end.peak - start.live).max(0) as u64,
+    };
+
+    (value, Some(stats))
+}
+
+#[cfg(feature = "count-allocations")]
+mod counting {
+    use super::COUNTERS;
+    use std::alloc::{GlobalAlloc, Layout, System};
+
+    #[global_allocator]
+    static ALLOCATOR: Counting = Counting;
+
+    struct Counting;
+
+    // SAFETY: every call is passed on to `System` unchanged. Counting doesn't allocate, a
+    // `const` thread local with no destructor needs no lazy initialization.
+    unsafe impl GlobalAlloc for Counting {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            let ptr = unsafe { System.alloc(layout) };
+            if !ptr.is_null() {
+                allocated(layout.size());
+            }
+            ptr
+        }
+
+        unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+            let ptr = unsafe { System.alloc_zeroed(layout) };
+            if !ptr.is_null() {
+                allocated(layout.size());
+            }
+            ptr
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            unsafe { System.dealloc(ptr, layout) };
+            freed(layout.size());
+        }
+
+        unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+            let new_ptr = unsafe { System.realloc(ptr, layout, new_size) };
+            if !new_ptr.is_null() {
+                freed(layout.size());
+                allocated(new_size);
+            }
+            new_ptr
+        }
+    }
+
+    fn allocated(size: usize) {
+        // Allocations while the thread shuts down are not counted.
+        let _ = COUNTERS.try_with(|counters| {
+            let mut c = counters.get();
+            c.allocations += 1;
+            c.bytes += size as u64;
+            c.live += size as i64;
+            c.peak = c.peak.max(c.live);
+            counters.set(c);
+        });
+    }
+
+    fn freed(size: usize) {
+        let _ = COUNTERS.try_with(|counters| {
+            let mut c = counters.get();
+            c.live -= size as i64;
+            counters.set(c);
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AllocStats, ENABLED, measure};
+    use std::hint::black_box;
+
+    #[test]
+    fn test_measure() {
+        let (len, stats) = measure(|| {
+            let small = black_box(vec![0u8; 100]);
+            drop(small);
+            black_box(vec![0u8; 1000]).len()
+        });
+        assert_eq!(1000, len);
+
+        if !ENABLED {
+            assert_eq!(None, stats);
+            return;
+        }
+
+        assert_eq!(
+            Some(AllocStats {
+                allocations: 2,
+                bytes: 1100,
+                peak: 1000
+            }),
+            stats
+        );
+
+        let (_, outer) = measure(|| {
+            let large = black_box(vec![0u8; 500]);
+            measure(|| black_box(vec![0u8; 10]));
+            drop(large);
+        });
+        assert_eq!(Some(510), outer.map(|stats| stats.peak));
+    }
+}
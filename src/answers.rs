@@ -0,0 +1,341 @@
+use crate::{
+    Part,
+    parse::{ParseError, ParseErrorKind, parse_number},
+};
+use std::{collections::BTreeMap, fmt::Display, str::FromStr};
+
+/// Known answers for the real inputs, read from a small subset of TOML:
+///
+/// ```toml
+/// # comment
+/// [01]
+/// part1 = 989
+/// part2 = "5941"
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Answers {
+    answers: BTreeMap<(u8, Part), String>,
+}
+
+impl Answers {
+    pub fn get(&self, day: u8, part: Part) -> Option<&str> {
+        self.answers.get(&(day, part)).map(String::as_str)
+    }
+
+    pub fn insert(&mut self, day: u8, part: Part, answer: impl Into<String>) {
+        self.answers.insert((day, part), answer.into());
+    }
+
+    /// Compares a computed answer with the known one.
+    pub fn check(&self, day: u8, part: Part, answer: Option<&str>) -> Verdict {
+        match (self.get(day, part), answer) {
+            (Some(expected), Some(answer)) if expected == answer => Verdict::Match,
+            (Some(expected), answer) => Verdict::Mismatch {
+                expected: expected.to_string(),
+                answer: answer.map(str::to_string),
+            },
+            (None, answer) => Verdict::Missing {
+                answer: answer.map(str::to_string),
+            },
+        }
+    }
+}
+
+impl FromStr for Answers {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut answers = Answers::default();
+        let mut day = None;
+
+        for (i, line) in s.lines().enumerate() {
+            let indent = line.len() - line.trim_start().len();
+            let line = strip_comment(line).trim();
+            let err = |column: usize, kind| ParseError::new(i + 1, indent + column + 1, kind);
+
+            if line.is_empty() {
+                continue;
+            }
+
+            if let Some(header) = line.strip_prefix('[') {
+                let header = header.strip_suffix(']').ok_or(err(
+                    line.len(),
+                    ParseErrorKind::UnexpectedEnd { expected: "']'" },
+                ))?;
+
+                day = Some(parse_number(header.trim()).map_err(|e| e.shift(i, indent + 1))?);
+                continue;
+            }
+
+            let Some((key, value)) = line.split_once('=') else {
+                return Err(err(
+                    line.len(),
+                    ParseErrorKind::UnexpectedEnd { expected: "'='" },
+                ));
+            };
+
+            let part = match key.trim() {
+                "part1" => Part::One,
+                "part2" => Part::Two,
+                key => {
+                    return Err(err(
+                        0,
+                        ParseErrorKind::UnexpectedChar {
+                            expected: "part1 or part2",
+                            found: key.chars().next().unwrap_or('='),
+                        },
+                    ));
+                }
+            };
+
+            let day = day.ok_or(err(
+                0,
+                ParseErrorKind::Missing {
+                    expected: "[day] header before the answers",
+                },
+            ))?;
+
+            let value = value.trim();
+            let answer = match value.strip_prefix('"') {
+                Some(quoted) => quoted.strip_suffix('"').ok_or(err(
+                    line.len(),
+                    ParseErrorKind::UnexpectedEnd { expected: "'\"'" },
+                ))?,
+                None => value,
+            };
+
+            if answer.is_empty() {
+                return Err(err(
+                    line.len(),
+                    ParseErrorKind::UnexpectedEnd { expected: "answer" },
+                ));
+            }
+
+            answers.insert(day, part, answer);
+        }
+
+        Ok(answers)
+    }
+}
+
+/// `text` of an answers file with the answer to `part` of `day` set and every other line, comments
+/// included, left as it is. A known answer is replaced in place, a new one goes after the
+/// last line of the day's section, or into a new section at the end.
+///
+/// `text` must parse as [`Answers`].
+pub fn set_answer(text: &str, day: u8, part: Part, answer: &str) -> String {
+    let entry = format!("part{part} = {answer:?}");
+    let mut lines: Vec<String> = text.lines().map(str::to_string).collect();
+
+    let mut section = None;
+    let mut replaced = false;
+    // Line after the last entry of the day, where a new entry goes.
+    let mut end_of_day = None;
+
+    for (i, line) in lines.iter_mut().enumerate() {
+        let content = strip_comment(line).trim();
+
+        if let Some(header) = content.strip_prefix('[') {
+            let header = header.strip_suffix(']').unwrap_or(header);
+            section = header.trim().parse::<u8>().ok();
+            if section == Some(day) {
+                end_of_day = Some(i + 1);
+            }
+            continue;
+        }
+
+        if section != Some(day) || content.is_empty() {
+            continue;
+        }
+
+        end_of_day = Some(i + 1);
+        if content
+            .split_once('=')
+            .is_some_and(|(key, _)| key.trim() == format!("part{part}"))
+        {
+            *line = entry.clone();
+            replaced = true;
+        }
+    }
+
+    match (replaced, end_of_day) {
+        (true, _) => {}
+        (false, Some(i)) => lines.insert(i, entry),
+        (false, None) => {
+            if lines.last().is_some_and(|line| !line.trim().is_empty()) {
+                lines.push(String::new());
+            }
+            lines.push(format!("[{day:02}]"));
+            lines.push(entry);
+        }
+    }
+
+    lines.join("\n") + "\n"
+}
+
+/// Answers never contain `#`, so everything after it is a comment.
+fn strip_comment(line: &str) -> &str {
+    line.split_once('#').map_or(line, |(before, _)| before)
+}
+
+impl Display for Answers {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut last_day = None;
+
+        for (&(day, part), answer) in &self.answers {
+            if last_day != Some(day) {
+                if last_day.is_some() {
+                    writeln!(f)?;
+                }
+                writeln!(f, "[{day:02}]")?;
+                last_day = Some(day);
+            }
+
+            writeln!(f, "part{part} = {answer:?}")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Outcome of checking a computed answer against the known one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Match,
+    /// `answer` is `None` if the part is not solved.
+    Mismatch {
+        expected: String,
+        answer: Option<String>,
+    },
+    /// No known answer to compare with.
+    Missing {
+        answer: Option<String>,
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Answers, Verdict, set_answer};
+    use crate::{
+        Part,
+        parse::{ParseError, ParseErrorKind},
+    };
+
+    const TEST: &str = r#"# Real input answers
+[01]
+part1 = 989 # trailing comment
+part2 = "5941"
+
+[7]
+part1 = 1537
+"#;
+
+    #[test]
+    fn test_parse() {
+        let answers: Answers = TEST.parse().unwrap();
+
+        assert_eq!(Some("989"), answers.get(1, Part::One));
+        assert_eq!(Some("5941"), answers.get(1, Part::Two));
+        assert_eq!(Some("1537"), answers.get(7, Part::One));
+        assert_eq!(None, answers.get(7, Part::Two));
+    }
+
+    #[test]
+    fn test_display_roundtrip() {
+        let answers: Answers = TEST.parse().unwrap();
+
+        assert_eq!(
+            "[01]\npart1 = \"989\"\npart2 = \"5941\"\n\n[07]\npart1 = \"1537\"\n",
+            answers.to_string()
+        );
+        assert_eq!(answers, answers.to_string().parse().unwrap());
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            Err(ParseError::new(
+                1,
+                1,
+                ParseErrorKind::Missing {
+                    expected: "[day] header before the answers"
+                }
+            )),
+            "part1 = 1".parse::<Answers>()
+        );
+        assert_eq!(
+            Err(ParseError::new(
+                2,
+                3,
+                ParseErrorKind::UnexpectedChar {
+                    expected: "part1 or part2",
+                    found: 'p'
+                }
+            )),
+            "[1]\n  part3 = 1".parse::<Answers>()
+        );
+        assert_eq!(
+            Err(ParseError::new(
+                1,
+                3,
+                ParseErrorKind::UnexpectedChar {
+                    expected: "digit",
+                    found: 'x'
+                }
+            )),
+            "[0x]".parse::<Answers>()
+        );
+    }
+
+    #[test]
+    fn test_set_answer() {
+        assert_eq!(
+            TEST.replace("part1 = 989 # trailing comment", "part1 = \"990\""),
+            set_answer(TEST, 1, Part::One, "990")
+        );
+        assert_eq!(
+            TEST.replace("part1 = 1537", "part1 = 1537\npart2 = \"42\""),
+            set_answer(TEST, 7, Part::Two, "42")
+        );
+        assert_eq!(
+            format!("{TEST}\n[12]\npart1 = \"5\"\n"),
+            set_answer(TEST, 12, Part::One, "5")
+        );
+        assert_eq!(
+            "# header\n[03]\npart1 = \"1\"\n",
+            set_answer("# header\n[03]", 3, Part::One, "1")
+        );
+        assert_eq!("[01]\npart2 = \"7\"\n", set_answer("", 1, Part::Two, "7"));
+
+        let answers: Answers = set_answer(TEST, 7, Part::Two, "42").parse().unwrap();
+        assert_eq!(Some("42"), answers.get(7, Part::Two));
+        assert_eq!(Some("989"), answers.get(1, Part::One));
+    }
+
+    #[test]
+    fn test_check() {
+        let answers: Answers = TEST.parse().unwrap();
+
+        assert_eq!(Verdict::Match, answers.check(1, Part::One, Some("989")));
+        assert_eq!(
+            Verdict::Mismatch {
+                expected: "989".to_string(),
+                answer: Some("990".to_string())
+            },
+            answers.check(1, Part::One, Some("990"))
+        );
+        assert_eq!(
+            Verdict::Mismatch {
+                expected: "1537".to_string(),
+                answer: None
+            },
+            answers.check(7, Part::One, None)
+        );
+        assert_eq!(
+            Verdict::Missing {
+                answer: Some("1".to_string())
+            },
+            answers.check(7, Part::Two, Some("1"))
+        );
+    }
+}
@@ -0,0 +1,222 @@
+use crate::{AnySolution, ParseError, Part};
+use std::{
+    fmt::Display,
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BenchOptions {
+    /// Untimed runs before measuring, to warm up caches and the allocator.
+    pub warmup: usize,
+    /// Timed runs per phase.
+    pub iterations: usize,
+}
+
+impl Default for BenchOptions {
+    fn default() -> Self {
+        BenchOptions {
+            warmup: 3,
+            iterations: 20,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    /// `None` for no samples.
+    pub fn from_samples(samples: &mut [Duration]) -> Option<Stats> {
+        if samples.is_empty() {
+            return None;
+        }
+
+        samples.sort();
+
+        let len = samples.len();
+        let median = if len.is_multiple_of(2) {
+            (samples[len / 2 - 1] + samples[len / 2]) / 2
+        } else {
+            samples[len / 2]
+        };
+
+        Some(Stats {
+            min: samples[0],
+            median,
+            mean: samples.iter().sum::<Duration>() / len as u32,
+            max: samples[len - 1],
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct DayBench {
+    pub day: u8,
+    pub parse: Stats,
+    /// `None` if the part is not solved yet.
+    pub part1: Option<Stats>,
+    pub part2: Option<Stats>,
+}
+
+/// Times parsing and both parts separately. Parts run on one parsed input.
+///
+/// Panics if `options.iterations` is 0.
+pub fn bench(
+    solution: &dyn AnySolution,
+    input: &str,
+    options: BenchOptions,
+) -> Result<DayBench, ParseError> {
+    assert!(
+        options.iterations > 0,
+        "Benchmark needs at least one iteration"
+    );
+
+    let parsed = solution.parse(input)?;
+
+    let parse = measure(options, || {
+        let start = Instant::now();
+        let parsed = black_box(solution.parse(black_box(input)));
+        let elapsed = start.elapsed();
+        drop(parsed);
+        elapsed
+    });
+
+    let part = |part: Part| -> Option<Stats> {
+        // A part without an answer for the input still takes its time to find that out.
+        if let Ok(None) = solution.solve(parsed.as_ref(), part) {
+            return None;
+        }
+
+        Some(measure(options, || {
+            let start = Instant::now();
+            let _ = black_box(solution.solve(black_box(parsed.as_ref()), part));
+            start.elapsed()
+        }))
+    };
+
+    Ok(DayBench {
+        day: solution.day(),
+        parse,
+        part1: part(Part::One),
+        part2: part(Part::Two),
+    })
+}
+
+fn measure(options: BenchOptions, mut run: impl FnMut() -> Duration) -> Stats {
+    for _ in 0..options.warmup {
+        run();
+    }
+
+    let mut samples: Vec<Duration> = (0..options.iterations).map(|_| run()).collect();
+
+    Stats::from_samples(&mut samples).expect("at least one iteration")
+}
+
+impl Display for DayBench {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "Day {:02}    {:>10} {:>10} {:>10} {:>10}",
+            self.day, "min", "median", "mean", "max"
+        )?;
+
+        let rows = [
+            ("parse", Some(self.parse)),
+            ("part 1", self.part1),
+            ("part 2", self.part2),
+        ];
+
+        for (name, stats) in rows {
+            match stats {
+                Some(stats) => writeln!(
+                    f,
+                    "  {name:<8} {:>10} {:>10} {:>10} {:>10}",
+                    format_duration(stats.min),
+                    format_duration(stats.median),
+                    format_duration(stats.mean),
+                    format_duration(stats.max),
+                )?,
+                None => writeln!(f, "  {name:<8} {:>10}", "not solved yet")?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Three significant digits in the largest fitting unit, e.g. `12.3µs`.
+pub fn format_duration(duration: Duration) -> String {
+    let nanos = duration.as_nanos() as f64;
+
+    let (value, unit) = match nanos {
+        n if n < 1e3 => (n, "ns"),
+        n if n < 1e6 => (n / 1e3, "µs"),
+        n if n < 1e9 => (n / 1e6, "ms"),
+        n => (n / 1e9, "s"),
+    };
+
+    let precision = match value {
+        v if v < 10.0 => 2,
+        v if v < 100.0 => 1,
+        _ => 0,
+    };
+
+    format!("{value:.precision$}{unit}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{BenchOptions, Stats, bench, format_duration};
+    use crate::days::day01::Day01;
+    use std::time::Duration;
+
+    fn ms(n: u64) -> Duration {
+        Duration::from_millis(n)
+    }
+
+    #[test]
+    fn test_stats() {
+        assert_eq!(
+            Some(Stats {
+                min: ms(1),
+                median: ms(3),
+                mean: ms(4),
+                max: ms(9),
+            }),
+            Stats::from_samples(&mut [ms(9), ms(1), ms(3), ms(2), ms(5)])
+        );
+        assert_eq!(
+            Some(ms(3)),
+            Stats::from_samples(&mut [ms(4), ms(2)]).map(|s| s.median)
+        );
+        assert_eq!(None, Stats::from_samples(&mut []));
+    }
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!("999ns", format_duration(Duration::from_nanos(999)));
+        assert_eq!("1.50µs", format_duration(Duration::from_nanos(1500)));
+        assert_eq!("12.3ms", format_duration(Duration::from_micros(12_300)));
+        assert_eq!("2.00s", format_duration(Duration::from_secs(2)));
+    }
+
+    #[test]
+    fn test_bench() {
+        let options = BenchOptions {
+            warmup: 0,
+            iterations: 3,
+        };
+        let result = bench(&Day01, "L68\nR18", options).unwrap();
+
+        assert_eq!(1, result.day);
+        assert!(result.part1.is_some());
+        assert!(result.part2.is_some());
+        assert!(bench(&Day01, "X", options).is_err());
+    }
+}
@@ -0,0 +1,113 @@
+use std::{env, error::Error, fmt::Display, fs, io, path::PathBuf};
+
+pub const YEAR: u16 = 2025;
+
+/// Session cookie of the puzzle site, overriding the session file.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+
+/// Base URL of the puzzle site, e.g. a local stand-in server.
+pub const BASE_URL_VAR: &str = "AOC_BASE_URL";
+
+const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// `$AOC_BASE_URL`, or the real site if it is not set.
+pub fn base_url() -> String {
+    env::var(BASE_URL_VAR).unwrap_or_else(|_| DEFAULT_BASE_URL.to_string())
+}
+
+/// `$XDG_CONFIG_HOME/aoc/session`, falling back to `~/.config/aoc/session`.
+pub fn session_file() -> Option<PathBuf> {
+    let config = env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))?;
+
+    Some(config.join("aoc").join("session"))
+}
+
+/// `$AOC_SESSION`, or the contents of [`session_file`].
+pub fn session() -> Result<String, SessionError> {
+    read_session(env::var(SESSION_VAR).ok(), session_file())
+}
+
+fn read_session(var: Option<String>, file: Option<PathBuf>) -> Result<String, SessionError> {
+    if let Some(session) = var.filter(|session| !session.trim().is_empty()) {
+        return Ok(session.trim().to_string());
+    }
+
+    let Some(file) = file else {
+        return Err(SessionError::Missing { file: None });
+    };
+
+    match fs::read_to_string(&file) {
+        Ok(session) if !session.trim().is_empty() => Ok(session.trim().to_string()),
+        Ok(_) => Err(SessionError::Missing { file: Some(file) }),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => {
+            Err(SessionError::Missing { file: Some(file) })
+        }
+        Err(err) => Err(SessionError::Io { file, err }),
+    }
+}
+
+#[derive(Debug)]
+pub enum SessionError {
+    Missing { file: Option<PathBuf> },
+    Io { file: PathBuf, err: io::Error },
+}
+
+impl Display for SessionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SessionError::Missing { file: Some(file) } => write!(
+                f,
+                "no session token. Set {SESSION_VAR} or write it to {}",
+                file.display()
+            ),
+            SessionError::Missing { file: None } => {
+                write!(f, "no session token. Set {SESSION_VAR}")
+            }
+            SessionError::Io { file, err } => {
+                write!(f, "failed to read {}: {err}", file.display())
+            }
+        }
+    }
+}
+
+impl Error for SessionError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            SessionError::Io { err, .. } => Some(err),
+            SessionError::Missing { .. } => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{SessionError, read_session};
+    use std::{env, fs, process};
+
+    #[test]
+    fn test_read_session() {
+        let file = env::temp_dir().join(format!("aoc-session-{}", process::id()));
+        fs::write(&file, "from-file\n").unwrap();
+
+        assert_eq!(
+            "from-var",
+            read_session(Some("from-var".to_string()), Some(file.clone())).unwrap()
+        );
+        assert_eq!(
+            "from-file",
+            read_session(Some(" ".to_string()), Some(file.clone())).unwrap()
+        );
+
+        fs::remove_file(&file).unwrap();
+        assert!(matches!(
+            read_session(None, Some(file.clone())),
+            Err(SessionError::Missing { file: Some(f) }) if f == file
+        ));
+        assert!(matches!(
+            read_session(None, None),
+            Err(SessionError::Missing { file: None })
+        ));
+    }
+}
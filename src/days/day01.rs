@@ -1,43 +1,48 @@
-use std::{
-    error::Error,
-    fs::File,
-    io::{BufRead, BufReader},
-    str::FromStr,
+use crate::{
+    Solution,
+    parse::{ParseError, ParseErrorKind, parse_lines, parse_number},
+    stream::{self, StreamError},
 };
+use std::{io::BufRead, str::FromStr};
 
 const MAX: u16 = 99;
 
-fn main() {
-    println!(
-        "Part1: {}",
-        part1(BufReader::new(File::open("files/01.txt").unwrap())).unwrap()
-    );
+pub struct Day01;
 
-    println!(
-        "Part2: {}",
-        part2(BufReader::new(File::open("files/01.txt").unwrap())).unwrap()
-    )
-}
+impl Solution for Day01 {
+    const DAY: u8 = 1;
 
-fn part1<R: BufRead>(reader: R) -> Result<usize, Box<dyn Error>> {
-    let mut dial = Dial::default();
+    type Input = Vec<Rotation>;
+    type Output = usize;
 
-    Ok(read_input(reader)?
-        .into_iter()
-        .filter(|rotation| {
-            dial.rotate(*rotation);
-            dial.point == 0
-        })
-        .count())
-}
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        read_input(input)
+    }
 
-fn part2<R: BufRead>(reader: R) -> Result<usize, Box<dyn Error>> {
-    let mut dial = Dial::default();
+    fn part1(rotations: &Self::Input) -> Option<usize> {
+        let mut dial = Dial::default();
+
+        Some(
+            rotations
+                .iter()
+                .filter(|rotation| {
+                    dial.rotate(**rotation);
+                    dial.point == 0
+                })
+                .count(),
+        )
+    }
+
+    fn part2(rotations: &Self::Input) -> Option<usize> {
+        let mut dial = Dial::default();
 
-    Ok(read_input(reader)?
-        .into_iter()
-        .map(|rotation| dial.rotate_part2(rotation))
-        .sum())
+        Some(
+            rotations
+                .iter()
+                .map(|rotation| dial.rotate_part2(*rotation))
+                .sum(),
+        )
+    }
 }
 
 #[derive(Debug, Copy, Clone, PartialEq)]
@@ -53,18 +58,36 @@ pub struct Rotation {
 }
 
 impl FromStr for Rotation {
-    type Err = Box<dyn Error>;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (d_str, v_str) = s.split_at(1);
-
-        let direction = match d_str {
-            "L" => Direction::L,
-            "R" => Direction::R,
-            _ => return Err(format!("Invalid direction: {d_str}").into()),
+        let mut chars = s.chars();
+
+        let direction = match chars.next() {
+            Some('L') => Direction::L,
+            Some('R') => Direction::R,
+            Some(found) => {
+                return Err(ParseError::new(
+                    1,
+                    1,
+                    ParseErrorKind::UnexpectedChar {
+                        expected: "'L' or 'R'",
+                        found,
+                    },
+                ));
+            }
+            None => {
+                return Err(ParseError::new(
+                    1,
+                    1,
+                    ParseErrorKind::UnexpectedEnd {
+                        expected: "'L' or 'R'",
+                    },
+                ));
+            }
         };
 
-        let value = v_str.parse()?;
+        let value = parse_number(chars.as_str()).map_err(|err| err.shift(0, 1))?;
 
         Ok(Rotation { direction, value })
     }
@@ -146,16 +169,34 @@ impl Default for Dial {
     }
 }
 
-#[allow(unused)]
-fn read_input<R: BufRead>(reader: R) -> Result<Vec<Rotation>, Box<dyn Error>> {
-    reader.lines().map(|line| line?.parse()).collect()
+fn read_input(input: &str) -> Result<Vec<Rotation>, ParseError> {
+    parse_lines(input)
+}
+
+/// Both answers in a single pass over `reader`, with one rotation in memory at a time.
+pub fn solve_stream(reader: impl BufRead) -> Result<[Option<usize>; 2], StreamError> {
+    let mut dial = Dial::default();
+    let (mut stops, mut passes) = (0, 0);
+
+    stream::for_each_line(reader, |line| {
+        // Turns the dial like `rotate`, counting every click on 0 on the way.
+        passes += dial.rotate_part2(line.parse()?);
+        if dial.point == 0 {
+            stops += 1;
+        }
+        Ok(())
+    })?;
+
+    Ok([Some(stops), Some(passes)])
 }
 
 #[cfg(test)]
 mod tests {
-    use std::io::BufReader;
-
-    use crate::{Dial, Direction, Rotation, part1, part2, read_input};
+    use super::{Day01, Dial, Direction, Rotation, read_input, solve_stream};
+    use crate::{
+        parse::{ParseError, ParseErrorKind},
+        test_support::examples,
+    };
 
     const TEST: &str = r#"L68
 L30
@@ -168,9 +209,16 @@ L99
 R14
 L82"#;
 
+    examples! {
+        Day01 {
+            TEST => part1: 3, part2: 6;
+            "R1000" => part1: 0, part2: 10;
+        }
+    }
+
     #[test]
     fn test_read_input() {
-        let rotation = read_input(BufReader::new(TEST.as_bytes())).unwrap();
+        let rotation = read_input(TEST).unwrap();
 
         assert_eq!(
             Some(Rotation {
@@ -189,10 +237,36 @@ L82"#;
         );
     }
 
+    #[test]
+    fn test_read_input_error() {
+        assert_eq!(
+            Err(ParseError::new(
+                2,
+                3,
+                ParseErrorKind::UnexpectedChar {
+                    expected: "digit",
+                    found: 'x'
+                }
+            )),
+            read_input("L68\nR4x")
+        );
+        assert_eq!(
+            Err(ParseError::new(
+                1,
+                1,
+                ParseErrorKind::UnexpectedChar {
+                    expected: "'L' or 'R'",
+                    found: 'X'
+                }
+            )),
+            read_input("X1")
+        );
+    }
+
     #[test]
     fn test_rotation() {
         let mut dial = Dial::default();
-        let rotations = read_input(TEST.as_bytes()).unwrap();
+        let rotations = read_input(TEST).unwrap();
 
         assert_eq!(dial.point, 50);
 
@@ -228,12 +302,21 @@ L82"#;
     }
 
     #[test]
-    fn test_part1() {
-        assert_eq!(3, part1(BufReader::new(TEST.as_bytes())).unwrap());
-    }
-
-    #[test]
-    fn test_part2() {
-        assert_eq!(6, part2(TEST.as_bytes()).unwrap());
+    fn test_solve_stream() {
+        assert_eq!([Some(3), Some(6)], solve_stream(TEST.as_bytes()).unwrap());
+        assert_eq!(
+            ParseError::new(
+                3,
+                1,
+                ParseErrorKind::UnexpectedChar {
+                    expected: "'L' or 'R'",
+                    found: 'X'
+                }
+            )
+            .to_string(),
+            solve_stream("L1\nR2\nX3\n".as_bytes())
+                .unwrap_err()
+                .to_string()
+        );
     }
 }
@@ -0,0 +1,223 @@
+use crate::{
+    ParseError, Solution,
+    range_set::{RangeSet, parse_range},
+    stream::{self, StreamError},
+};
+use std::{io::BufRead, ops::RangeInclusive};
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+
+    type Input = RangeSet;
+    type Output = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(ranges: &Self::Input) -> Option<u64> {
+        Some(ranges.iter().flat_map(find_invalid_ids).sum())
+    }
+
+    fn part2(ranges: &Self::Input) -> Option<u64> {
+        Some(ranges.iter().flat_map(find_invalid_ids_part2).sum())
+    }
+}
+
+fn parse_input(input: &str) -> Result<RangeSet, ParseError> {
+    input.parse()
+}
+
+/// Both answers, tokenizing the list as it is read. Only the ranges are kept, never the text,
+/// since overlapping ranges must be merged before counting.
+pub fn solve_stream(reader: impl BufRead) -> Result<[Option<u64>; 2], StreamError> {
+    let mut ranges = Vec::new();
+
+    stream::for_each_token(
+        reader,
+        |byte| byte == b',' || byte.is_ascii_whitespace(),
+        |token| {
+            ranges.push(parse_range(token)?);
+            Ok(())
+        },
+    )?;
+
+    let ranges: RangeSet = ranges.into_iter().collect();
+    Ok([Day02::part1(&ranges), Day02::part2(&ranges)])
+}
+
+fn find_invalid_ids(ids: RangeInclusive<u64>) -> Vec<u64> {
+    ids.into_iter()
+        .filter(|&num| is_repeated_twice(num))
+        .collect()
+}
+
+fn find_invalid_ids_part2(ids: RangeInclusive<u64>) -> Vec<u64> {
+    ids.into_iter().filter(|&num| is_repeated(num)).collect()
+}
+
+fn is_repeated_twice(num: u64) -> bool {
+    let mut buf = [0; 20];
+    let s = digits(num, &mut buf);
+
+    let len = s.len();
+    if !len.is_multiple_of(2) {
+        return false;
+    }
+
+    let (l, r) = s.split_at(len / 2);
+
+    l == r
+}
+
+const MAX_CHUNK_SIZE: usize = 30;
+fn is_repeated(num: u64) -> bool {
+    let mut buf = [0; 20];
+    let s = digits(num, &mut buf);
+
+    // A pattern must appear at least twice, so we only need to check for
+    // chunk lengths up to half the total string length.
+    for chunk_size in 1..=s.len() / 2 {
+        if chunk_size > MAX_CHUNK_SIZE {
+            break;
+        }
+
+        if is_repeated_chunk_size(s, chunk_size) {
+            return true;
+        }
+    }
+
+    false
+}
+
+fn is_repeated_chunk_size(s: &[u8], chunk_size: usize) -> bool {
+    if chunk_size == 0 {
+        return s.is_empty();
+    }
+
+    if !s.len().is_multiple_of(chunk_size) {
+        return false;
+    }
+
+    let mut chunks = s.chunks(chunk_size);
+
+    chunks
+        .next()
+        .is_none_or(|first| chunks.all(|chunk| chunk == first))
+}
+
+/// The decimal digits of `num` in `buf`, without allocating a string for every ID.
+fn digits(mut num: u64, buf: &mut [u8; 20]) -> &[u8] {
+    let mut start = buf.len();
+
+    loop {
+        start -= 1;
+        buf[start] = b'0' + (num % 10) as u8;
+        num /= 10;
+
+        if num == 0 {
+            return &buf[start..];
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        Day02, digits, find_invalid_ids, is_repeated, is_repeated_chunk_size, is_repeated_twice,
+        parse_input, solve_stream,
+    };
+    use crate::{
+        parse::{ParseError, ParseErrorKind},
+        test_support::examples,
+    };
+    use std::io::BufReader;
+
+    const TEST: &str = "11-22,95-115,998-1012,1188511880-1188511890,222220-222224,1698522-1698528,446443-446449,38593856-38593862,565653-565659,824824821-824824827,2121212118-2121212124";
+
+    examples! {
+        Day02 {
+            TEST => part1: 1227775554, part2: 4174379265;
+        }
+    }
+
+    #[test]
+    fn test_find_invalid_ids() {
+        assert_eq!(vec![11, 22], find_invalid_ids(11..=22));
+        assert_eq!(vec![99], find_invalid_ids(99..=115));
+        assert_eq!(vec![1010], find_invalid_ids(998..=1012));
+        assert_eq!(vec![1188511885], find_invalid_ids(1188511880..=1188511890));
+        assert_eq!(vec![222222], find_invalid_ids(222220..=222224));
+        assert_eq!(Vec::<u64>::new(), find_invalid_ids(1698522..=1698528));
+        assert_eq!(vec![446446], find_invalid_ids(446443..=446449));
+        assert_eq!(vec![38593859], find_invalid_ids(38593856..=38593862));
+        assert_eq!(Vec::<u64>::new(), find_invalid_ids(565653..=565659));
+        assert_eq!(Vec::<u64>::new(), find_invalid_ids(824824821..=824824827));
+        assert_eq!(Vec::<u64>::new(), find_invalid_ids(2121212118..=2121212124));
+    }
+
+    #[test]
+    fn test_parse_input() {
+        assert_eq!(
+            vec![11..=22, 5142771457..=5142940464],
+            parse_input("11-22,5142771457-5142940464").unwrap().ranges()
+        )
+    }
+
+    #[test]
+    fn test_digits() {
+        let mut buf = [0; 20];
+        assert_eq!(b"0", digits(0, &mut buf));
+        assert_eq!(b"1010", digits(1010, &mut buf));
+        assert_eq!(u64::MAX.to_string().as_bytes(), digits(u64::MAX, &mut buf));
+    }
+
+    #[test]
+    fn test_is_repeated_twice() {
+        assert!(is_repeated_twice(22));
+        assert!(is_repeated_twice(123123));
+        assert!(!is_repeated_twice(1235123));
+    }
+
+    #[test]
+    fn test_is_repeated_chunk_size() {
+        assert!(is_repeated_chunk_size(b"12121212", 2));
+        assert!(is_repeated_chunk_size(b"12341234", 4));
+        assert!(!is_repeated_chunk_size(b"12341234", 2));
+    }
+
+    #[test]
+    fn test_is_repeated() {
+        assert!(is_repeated(12121212));
+        assert!(is_repeated(12341234));
+        assert!(!is_repeated(123451234));
+        assert!(!is_repeated(12));
+    }
+
+    #[test]
+    fn test_solve_stream() {
+        // A small buffer splits ranges across reads.
+        let reader = BufReader::with_capacity(4, TEST.as_bytes());
+        assert_eq!(
+            [Some(1227775554), Some(4174379265)],
+            solve_stream(reader).unwrap()
+        );
+
+        assert_eq!(
+            ParseError::new(
+                2,
+                4,
+                ParseErrorKind::UnexpectedChar {
+                    expected: "digit",
+                    found: 'x'
+                }
+            )
+            .to_string(),
+            solve_stream("11-22,\n95-x".as_bytes())
+                .unwrap_err()
+                .to_string()
+        );
+    }
+}
@@ -0,0 +1,340 @@
+use crate::{
+    Part, Solution,
+    parse::{ParseError, ParseErrorKind, parse_lines},
+    stream::{self, StreamError},
+};
+use std::{io::BufRead, str::FromStr};
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+
+    type Input = Vec<Bank>;
+    type Output = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(banks: &Self::Input) -> Option<u64> {
+        banks
+            .iter()
+            .try_fold(0u64, |total, bank| total.checked_add(bank.pair_joltage()))
+    }
+
+    fn part2(banks: &Self::Input) -> Option<u64> {
+        banks.iter().try_fold(0u64, |total, bank| {
+            total.checked_add(bank.joltage(BATTERIES_ON)?)
+        })
+    }
+
+    fn unanswered(banks: &Self::Input, part: Part) -> Option<String> {
+        let short = banks.iter().any(|bank| bank.batteries.len() < BATTERIES_ON);
+        Some(unanswered(part == Part::Two && short))
+    }
+}
+
+/// Batteries turned on per bank in part 2.
+const BATTERIES_ON: usize = 12;
+
+/// Why a total is missing: a bank too short for part 2, or a total too large.
+fn unanswered(short: bool) -> String {
+    match short {
+        true => format!("a bank has fewer than {BATTERIES_ON} batteries"),
+        false => format!("the total joltage is more than {}", u64::MAX),
+    }
+}
+
+fn parse_input(input: &str) -> Result<Vec<Bank>, ParseError> {
+    parse_lines(input)
+}
+
+/// Both answers in a single pass over `reader`, with one bank in memory at a time. An
+/// answer is an error if the input has none, like in [`Solution::unanswered`].
+pub fn solve_stream(reader: impl BufRead) -> Result<[Result<u64, String>; 2], StreamError> {
+    let mut totals = [Some(0u64); 2];
+    let mut short = false;
+
+    stream::for_each_line(reader, |line| {
+        let bank: Bank = line.parse()?;
+        let [pair, twelve] = &mut totals;
+
+        *pair = pair.and_then(|total| total.checked_add(bank.pair_joltage()));
+        *twelve = twelve
+            .zip(bank.joltage(BATTERIES_ON))
+            .and_then(|(total, j)| total.checked_add(j));
+        short |= bank.batteries.len() < BATTERIES_ON;
+        Ok(())
+    })?;
+
+    let [pair, twelve] = totals;
+    Ok([
+        pair.ok_or_else(|| unanswered(false)),
+        twelve.ok_or_else(|| unanswered(short)),
+    ])
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Bank {
+    batteries: Vec<u8>,
+}
+
+impl FromStr for Bank {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let batteries: Vec<u8> = s
+            .chars()
+            .enumerate()
+            .map(|(i, ch)| {
+                ch.to_digit(10).map(|d| d as u8).ok_or(ParseError::new(
+                    1,
+                    i + 1,
+                    ParseErrorKind::UnexpectedChar {
+                        expected: "digit",
+                        found: ch,
+                    },
+                ))
+            })
+            .collect::<Result<_, _>>()?;
+
+        // Part 1 turns on two of them.
+        if batteries.len() < 2 {
+            return Err(ParseError::new(
+                1,
+                batteries.len() + 1,
+                ParseErrorKind::Count {
+                    items: "batteries or more",
+                    expected: 2,
+                    found: batteries.len(),
+                },
+            ));
+        }
+
+        Ok(Bank { batteries })
+    }
+}
+
+impl Bank {
+    #[allow(unused)]
+    const fn new(batteries: Vec<u8>) -> Bank {
+        Bank { batteries }
+    }
+
+    fn pair_joltage(&self) -> u64 {
+        let (a, b) = self.find_highest_pair();
+        u64::from(a) * 10 + u64::from(b)
+    }
+
+    /// Joltage of the `n` batteries that give the most. `None` if the bank has fewer than `n`
+    /// batteries, or if the joltage doesn't fit in a `u64`, which takes more than 19 of them.
+    fn joltage(&self, n: usize) -> Option<u64> {
+        if self.batteries.len() < n {
+            return None;
+        }
+
+        self.find_n_highest(n)
+            .into_iter()
+            .try_fold(0u64, |joltage, value| {
+                joltage.checked_mul(10)?.checked_add(u64::from(value))
+            })
+    }
+
+    fn find_highest_pair(&self) -> (u8, u8) {
+        self.get_pair(self.find_highest_pair_indices())
+    }
+
+    fn get_pair(&self, (a_idx, b_idx): (usize, usize)) -> (u8, u8) {
+        (self.batteries[a_idx], self.batteries[b_idx])
+    }
+
+    fn find_n_highest(&self, n: usize) -> Vec<u8> {
+        let mut start = 0;
+        let mut values = vec![];
+
+        for r in (1..=n).rev() {
+            let idx = self.find_highest_index(start, r);
+            let value = self.batteries[idx];
+            values.push(value);
+            start = idx + 1;
+        }
+
+        values
+    }
+
+    fn find_highest_index(&self, start: usize, remaining_n_to_find: usize) -> usize {
+        let len = self.batteries.len();
+
+        let mut max = 0;
+        let mut max_idx = start;
+
+        for (i, a) in self.batteries[start..=(len - remaining_n_to_find)]
+            .iter()
+            .enumerate()
+        {
+            if *a > max {
+                max = *a;
+                max_idx = start + i;
+            }
+        }
+
+        max_idx
+    }
+
+    fn find_highest_pair_indices(&self) -> (usize, usize) {
+        let len: usize = self.batteries.len();
+
+        for a_candidate in (0..=9).rev() {
+            for (i, batterry_a) in self.batteries.iter().take(len - 1).enumerate() {
+                if *batterry_a == a_candidate {
+                    for b_candidate in (0..=9).rev() {
+                        for (j, batterry_b) in self.batteries.iter().skip(i + 1).enumerate() {
+                            if *batterry_b == b_candidate {
+                                return (i, i + 1 + j);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        (len - 2, len - 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Bank, Day03, parse_input, solve_stream};
+    use crate::{
+        AnySolution, Part, Solution,
+        parse::{ParseError, ParseErrorKind},
+        test_support::examples,
+    };
+
+    const TEST: &str = r#"987654321111111
+811111111111119
+234234234234278
+818181911112111"#;
+
+    examples! {
+        Day03 {
+            TEST => part1: 357, part2: 3121910778619;
+        }
+    }
+
+    #[test]
+    fn test_parse_input() {
+        assert_eq!(
+            vec![9, 8, 7, 6, 5, 4, 3, 2, 1, 1, 1, 1, 1, 1, 1],
+            parse_input(TEST).unwrap()[0].batteries
+        );
+    }
+
+    #[test]
+    fn test_parse_input_error() {
+        assert_eq!(
+            Err(ParseError::new(
+                2,
+                4,
+                ParseErrorKind::UnexpectedChar {
+                    expected: "digit",
+                    found: 'x'
+                }
+            )),
+            parse_input("987\n811x1")
+        );
+        assert_eq!(
+            Err(ParseError::new(
+                2,
+                2,
+                ParseErrorKind::Count {
+                    items: "batteries or more",
+                    expected: 2,
+                    found: 1
+                }
+            )),
+            parse_input("98\n1")
+        );
+        assert!(solve_stream("1\n".as_bytes()).is_err());
+    }
+
+    #[test]
+    fn test_short_banks() {
+        let banks = parse_input("98\n123456789012").unwrap();
+        let error = "a bank has fewer than 12 batteries".to_string();
+
+        assert_eq!(Some(190), Day03::part1(&banks));
+        assert_eq!(None, Day03::part2(&banks));
+        assert_eq!(Err(error.clone()), Day03.solve(&banks, Part::Two));
+        assert_eq!(
+            [Ok(190), Err(error)],
+            solve_stream("98\n123456789012".as_bytes()).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_find_highest_pair_indices() {
+        let bank = |s: &str| -> Bank { s.parse().unwrap() };
+
+        assert_eq!((0, 1), bank("987654321111111").find_highest_pair_indices());
+        assert_eq!((0, 14), bank("811111111111119").find_highest_pair_indices());
+        assert_eq!(
+            (13, 14),
+            bank("234234234234278").find_highest_pair_indices()
+        );
+        assert_eq!((6, 11), bank("818181911112111").find_highest_pair_indices());
+        assert_eq!((0, 1), bank("900").find_highest_pair_indices());
+    }
+
+    #[test]
+    fn test_find_highest_index() {
+        let bank = |s: &str| -> Bank { s.parse().unwrap() };
+
+        assert_eq!(0, bank("811111111111119").find_highest_index(0, 12));
+        assert_eq!(14, bank("234234234234278").find_highest_index(14, 1));
+        assert_eq!(14, bank("811111111111119").find_highest_index(12, 1));
+        assert_eq!(5, bank("987654321111111").find_highest_index(5, 7));
+        assert_eq!(1, bank("0100000000000").find_highest_index(0, 12));
+    }
+
+    #[test]
+    fn test_zero_batteries() {
+        let bank = |s: &str| -> Bank { s.parse().unwrap() };
+
+        assert_eq!(90, bank("900").pair_joltage());
+        assert_eq!(Some(100000000000), bank("0100000000000").joltage(12));
+        assert_eq!(Some(0), bank("000000000000").joltage(12));
+    }
+
+    #[test]
+    fn test_find_n_highest() {
+        let bank = |s: &str| -> Bank { s.parse().unwrap() };
+
+        assert_eq!(
+            vec![9, 8, 7, 6, 5, 4, 3, 2, 1, 1, 1, 1],
+            bank("987654321111111").find_n_highest(12)
+        );
+
+        assert_eq!(
+            vec![8, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 9],
+            bank("811111111111119").find_n_highest(12)
+        );
+        assert_eq!(
+            vec![4, 3, 4, 2, 3, 4, 2, 3, 4, 2, 7, 8],
+            bank("234234234234278").find_n_highest(12)
+        );
+        assert_eq!(
+            vec![8, 8, 8, 9, 1, 1, 1, 1, 2, 1, 1, 1],
+            bank("818181911112111").find_n_highest(12)
+        );
+    }
+
+    #[test]
+    fn test_solve_stream() {
+        assert_eq!(
+            [Ok(357), Ok(3121910778619)],
+            solve_stream(TEST.as_bytes()).unwrap()
+        );
+    }
+}
@@ -0,0 +1,197 @@
+use crate::{
+    ParseError, Solution,
+    grid::{self, Cell, Position},
+    visualize::{Frame, Tile},
+};
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+
+    type Input = Grid;
+    type Output = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input.parse()
+    }
+
+    fn part1(grid: &Self::Input) -> Option<usize> {
+        Some(get_removable_positions(grid).len())
+    }
+
+    fn part2(grid: &Self::Input) -> Option<usize> {
+        let mut grid = grid.clone();
+
+        let mut total_removed = 0;
+
+        loop {
+            let positions = get_removable_positions(&grid);
+
+            if positions.is_empty() {
+                return Some(total_removed);
+            }
+
+            total_removed += positions.len();
+
+            for pos in positions {
+                grid[pos] = Field::Empty;
+            }
+        }
+    }
+}
+
+pub type Grid = grid::Grid<Field>;
+
+/// Removable: number of adjacent paper fields < 4
+fn get_removable_positions(grid: &Grid) -> Vec<Position> {
+    grid.iter()
+        .filter(|(_, f)| **f == Field::Paper)
+        .filter_map(|(pos, _)| match get_adjacent_paper_n(grid, pos) {
+            0..=3 => Some(pos),
+            _ => None,
+        })
+        .collect()
+}
+
+fn get_adjacent_paper_n(grid: &Grid, pos: Position) -> usize {
+    grid.neighbours8(pos)
+        .filter(|&pos| grid[pos] == Field::Paper)
+        .count()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Field {
+    Empty,
+    Paper,
+}
+
+impl Cell for Field {
+    const EXPECTED: &'static str = "'.' or '@'";
+
+    fn from_char(ch: char) -> Option<Self> {
+        match ch {
+            '.' => Some(Field::Empty),
+            '@' => Some(Field::Paper),
+            _ => None,
+        }
+    }
+
+    fn to_char(&self) -> char {
+        match self {
+            Field::Empty => '.',
+            Field::Paper => '@',
+        }
+    }
+}
+
+/// Rounds removed paper keeps fading out before it is drawn as empty.
+const FADE_ROUNDS: usize = 3;
+
+/// The grid before and after every round of part 2.
+pub fn removal_frames(grid: &Grid) -> Vec<Frame> {
+    let mut grid = grid.clone();
+    // Round in which each paper was removed.
+    let mut removed_in = grid::Grid::filled(grid.width(), grid.height(), None);
+    let mut total_removed = 0;
+
+    let frame = |grid: &Grid, removed_in: &grid::Grid<Option<usize>>, round, caption| Frame {
+        caption,
+        tiles: grid::Grid::new(
+            grid.width(),
+            grid.height(),
+            grid.positions()
+                .map(|pos| match (grid[pos], removed_in[pos]) {
+                    (Field::Paper, _) => Tile::Paper,
+                    (Field::Empty, Some(removed)) if round - removed < FADE_ROUNDS => {
+                        Tile::Fading((round - removed) as u8)
+                    }
+                    (Field::Empty, _) => Tile::Empty,
+                })
+                .collect(),
+        ),
+    };
+
+    let mut frames = vec![frame(&grid, &removed_in, 0, "round 0".to_string())];
+
+    for round in 1.. {
+        let positions = get_removable_positions(&grid);
+        if positions.is_empty() {
+            break;
+        }
+
+        total_removed += positions.len();
+        for &pos in &positions {
+            grid[pos] = Field::Empty;
+            removed_in[pos] = Some(round);
+        }
+
+        let caption = format!(
+            "round {round}: removed {}, {total_removed} in total",
+            positions.len()
+        );
+        frames.push(frame(&grid, &removed_in, round, caption));
+    }
+
+    frames
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Day04, Field, Grid, removal_frames};
+    use crate::{grid::Position, test_support::examples, visualize::Tile};
+
+    const TEST: &str = r#"..@@.@@@@.
+@@@.@.@.@@
+@@@@@.@.@@
+@.@@@@..@.
+@@.@@@@.@@
+.@@@@@@@.@
+.@.@.@.@@@
+@.@@@.@@@@
+.@@@@@@@@.
+@.@.@@@.@."#;
+
+    examples! {
+        Day04 {
+            TEST => part1: 13, part2: 43;
+        }
+    }
+
+    fn test_grid() -> Grid {
+        TEST.parse().unwrap()
+    }
+
+    const E: Field = Field::Empty;
+    const P: Field = Field::Paper;
+
+    #[test]
+    fn test_parse_input() {
+        assert_eq!(
+            Some(&[E, E, P, P, E, P, P, P, P, E][..]),
+            test_grid().row(0)
+        );
+    }
+
+    #[test]
+    fn test_removal_frames() {
+        let frames = removal_frames(&test_grid());
+
+        assert_eq!("round 0", frames[0].caption);
+        assert_eq!(
+            "round 1: removed 13, 13 in total",
+            frames[1].caption.as_str()
+        );
+        assert!(frames.last().unwrap().caption.ends_with(", 43 in total"));
+        assert_eq!(Tile::Fading(0), frames[1].tiles[Position::new(0, 2)]);
+        assert_eq!(Tile::Fading(1), frames[2].tiles[Position::new(0, 2)]);
+        assert_eq!(Tile::Empty, frames[4].tiles[Position::new(0, 2)]);
+    }
+
+    #[test]
+    fn test_grid_get() {
+        let grid = test_grid();
+        assert_eq!(Some(&E), grid.get(Position::new(0, 4)));
+        assert_eq!(None, grid.get(Position::new(0, 10)));
+    }
+}
@@ -0,0 +1,164 @@
+use crate::{
+    ParseError, Part, Solution,
+    parse::parse_number,
+    range_set::{RangeSet, parse_range},
+};
+use std::str::FromStr;
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+
+    type Input = Database;
+    type Output = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input.parse()
+    }
+
+    fn part1(database: &Self::Input) -> Option<u64> {
+        Some(
+            database
+                .available
+                .iter()
+                .filter(|&&f| database.is_fresh(f))
+                .count() as u64,
+        )
+    }
+
+    fn part2(database: &Self::Input) -> Option<u64> {
+        database.fresh.checked_len()
+    }
+
+    fn unanswered(_database: &Self::Input, part: Part) -> Option<String> {
+        match part {
+            Part::One => None,
+            Part::Two => Some(format!("more than {} fresh IDs", u64::MAX)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Database {
+    fresh: RangeSet,
+    available: Vec<u64>,
+}
+
+impl Database {
+    fn is_fresh(&self, a: u64) -> bool {
+        self.fresh.contains(a)
+    }
+}
+
+impl FromStr for Database {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut fresh = Vec::new();
+        let mut available = Vec::new();
+        let mut parsing_fresh = true;
+
+        for (i, line) in s.lines().enumerate() {
+            if line.is_empty() {
+                parsing_fresh = false;
+                continue;
+            }
+
+            if parsing_fresh {
+                fresh.push(parse_range(line).map_err(|err| err.shift(i, 0))?);
+            } else {
+                available.push(parse_number(line).map_err(|err| err.shift(i, 0))?);
+            }
+        }
+
+        Ok(Database {
+            fresh: fresh.into_iter().collect(),
+            available,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Database, Day05};
+    use crate::{
+        AnySolution, Part,
+        parse::{ParseError, ParseErrorKind},
+        test_support::examples,
+    };
+
+    const TEST: &str = r#"3-5
+10-14
+16-20
+12-18
+
+1
+5
+8
+11
+17
+32"#;
+
+    examples! {
+        Day05 {
+            TEST => part1: 3, part2: 14;
+        }
+    }
+
+    fn test_db() -> Database {
+        TEST.parse().unwrap()
+    }
+
+    #[test]
+    fn test_parse_database() {
+        assert_eq!(
+            Database {
+                fresh: [3..=5, 10..=14, 16..=20, 12..=18].into_iter().collect(),
+                available: vec![1, 5, 8, 11, 17, 32],
+            },
+            test_db()
+        );
+    }
+
+    #[test]
+    fn test_parse_database_error() {
+        assert_eq!(
+            Err(ParseError::new(
+                4,
+                2,
+                ParseErrorKind::UnexpectedChar {
+                    expected: "digit",
+                    found: '-'
+                }
+            )),
+            "3-5\n10-14\n\n1-2".parse::<Database>()
+        );
+    }
+
+    #[test]
+    fn test_every_id_fresh() {
+        let database = AnySolution::parse(&Day05, "0-18446744073709551615\n\n1").unwrap();
+
+        assert_eq!(
+            Ok(Some("1".to_string())),
+            Day05.solve(database.as_ref(), Part::One)
+        );
+        assert_eq!(
+            Err(format!("more than {} fresh IDs", u64::MAX)),
+            Day05.solve(database.as_ref(), Part::Two)
+        );
+    }
+
+    #[test]
+    fn test_is_fresh() {
+        let db = test_db();
+
+        assert!(!db.is_fresh(1));
+        assert!(db.is_fresh(5));
+        assert!(!db.is_fresh(8));
+        assert!(db.is_fresh(11));
+        assert!(db.is_fresh(17));
+        assert!(!db.is_fresh(32));
+    }
+}
@@ -0,0 +1,388 @@
+use crate::{
+    Part, Solution,
+    parse::{ParseError, ParseErrorKind, parse_number, tokens},
+};
+use std::str::FromStr;
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+
+    type Input = Worksheet;
+    type Output = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let rows = Rows::parse(input)?;
+
+        // Lines after the signs are not part of the worksheet.
+        let lines: Vec<&str> = input.lines().take(rows.nums.len() + 1).collect();
+
+        Ok(Worksheet {
+            by_columns: parse_columns(&lines)?,
+            by_rows: rows.into_operations(),
+        })
+    }
+
+    fn part1(worksheet: &Self::Input) -> Option<usize> {
+        grand_total(&worksheet.by_rows)
+    }
+
+    fn part2(worksheet: &Self::Input) -> Option<usize> {
+        grand_total(&worksheet.by_columns)
+    }
+
+    fn unanswered(_worksheet: &Self::Input, _part: Part) -> Option<String> {
+        Some(format!(
+            "a result or the grand total is more than {}",
+            usize::MAX
+        ))
+    }
+}
+
+/// Sum of the results of the problems, `None` if it or one of the results doesn't fit in a
+/// `usize`.
+fn grand_total(operations: &[Operation]) -> Option<usize> {
+    operations
+        .iter()
+        .try_fold(0usize, |total, op| total.checked_add(op.calc()?))
+}
+
+/// The worksheet read both ways: row by row for part 1, column by column for part 2.
+#[derive(Debug, Clone)]
+pub struct Worksheet {
+    by_rows: Vec<Operation>,
+    by_columns: Vec<Operation>,
+}
+
+/// Reads the problems column by column: every column holds one number written top to bottom,
+/// problems are separated by blank columns and the sign sits under the first column.
+///
+/// `lines` are the number lines followed by the line of signs.
+#[allow(clippy::needless_range_loop)]
+fn parse_columns(lines: &[&str]) -> Result<Vec<Operation>, ParseError> {
+    let grid: Vec<Vec<char>> = lines.iter().map(|line| line.chars().collect()).collect();
+
+    let max_col = grid.iter().map(|row| row.len()).max().unwrap_or(0);
+
+    let sign_row_idx = grid.len() - 1;
+    let at = |row: usize, col: usize| grid[row].get(col).copied().unwrap_or(' ');
+
+    let mut operations = vec![];
+    let mut current: Option<Operation> = None;
+
+    for col in 0..max_col {
+        let mut value_s = String::new();
+
+        for row in 0..sign_row_idx {
+            match at(row, col) {
+                ' ' => {}
+                ch if ch.is_ascii_digit() => value_s.push(ch),
+                found => {
+                    return Err(ParseError::new(
+                        row + 1,
+                        col + 1,
+                        ParseErrorKind::UnexpectedChar {
+                            expected: "digit or ' '",
+                            found,
+                        },
+                    ));
+                }
+            }
+        }
+
+        // if no values in entire column, operands are ready
+        if value_s.is_empty() {
+            operations.extend(current.take());
+            continue;
+        }
+
+        let value: usize = parse_number(&value_s).map_err(|err| err.shift(0, col))?;
+
+        let operation = match &mut current {
+            Some(operation) => operation,
+            None => {
+                let sign = Sign::from_char(at(sign_row_idx, col)).ok_or(ParseError::new(
+                    sign_row_idx + 1,
+                    col + 1,
+                    ParseErrorKind::UnexpectedChar {
+                        expected: "'+' or '*'",
+                        found: at(sign_row_idx, col),
+                    },
+                ))?;
+
+                current.insert(Operation {
+                    operands: vec![],
+                    sign,
+                })
+            }
+        };
+
+        operation.operands.push(value);
+    }
+
+    operations.extend(current);
+
+    Ok(operations)
+}
+
+#[derive(Debug, Clone, Default)]
+struct Operation {
+    operands: Vec<usize>,
+    sign: Sign,
+}
+
+impl Operation {
+    /// `None` if the result doesn't fit in a `usize`.
+    fn calc(&self) -> Option<usize> {
+        let mut operands = self.operands.iter().copied();
+
+        match self.sign {
+            Sign::Add => operands.try_fold(0, usize::checked_add),
+            Sign::Mul => operands.try_fold(1, usize::checked_mul),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Rows {
+    nums: Vec<Vec<u16>>,
+    signs: Vec<Sign>,
+}
+
+impl Rows {
+    #[allow(clippy::needless_range_loop)]
+    fn into_operations(self) -> Vec<Operation> {
+        let Rows { nums, signs } = self;
+
+        let operands_n = nums.len();
+        let problems_n = nums[0].len();
+
+        let mut operations: Vec<Operation> = Vec::new();
+
+        for x in 0..problems_n {
+            let mut operands: Vec<usize> = vec![];
+            for y in 0..operands_n {
+                operands.push(nums[y][x] as usize);
+            }
+
+            operations.push(Operation {
+                operands,
+                sign: signs[x],
+            });
+        }
+
+        operations
+    }
+
+    /// Every line has the same number of problems.
+    fn parse(input: &str) -> Result<Rows, ParseError> {
+        let mut nums: Vec<Vec<u16>> = Vec::new();
+        let mut lines_n = 0;
+
+        for (i, line) in input.lines().enumerate() {
+            lines_n += 1;
+
+            // The first line that doesn't start with a number holds the signs.
+            let is_sign_line = line
+                .trim_start()
+                .starts_with(|ch: char| !ch.is_ascii_digit());
+
+            let problems_n = if is_sign_line {
+                let signs = tokens(line)
+                    .map(|(col, s)| s.parse::<Sign>().map_err(|err| err.shift(i, col)))
+                    .collect::<Result<Vec<Sign>, _>>()?;
+
+                if nums.is_empty() {
+                    return Err(ParseError::new(
+                        i + 1,
+                        1,
+                        ParseErrorKind::Missing {
+                            expected: "line of numbers",
+                        },
+                    ));
+                }
+
+                check_problems_n(nums[0].len(), signs.len(), i, "signs")?;
+
+                return Ok(Rows { nums, signs });
+            } else {
+                let nums_line = tokens(line)
+                    .map(|(col, s)| parse_number::<u16>(s).map_err(|err| err.shift(i, col)))
+                    .collect::<Result<Vec<u16>, _>>()?;
+
+                let problems_n = nums_line.len();
+                nums.push(nums_line);
+                problems_n
+            };
+
+            check_problems_n(nums[0].len(), problems_n, i, "numbers")?;
+        }
+
+        Err(ParseError::new(
+            lines_n + 1,
+            1,
+            ParseErrorKind::Missing {
+                expected: "line of operation signs",
+            },
+        ))
+    }
+}
+
+fn check_problems_n(
+    expected: usize,
+    found: usize,
+    line_idx: usize,
+    items: &'static str,
+) -> Result<(), ParseError> {
+    if expected == found {
+        return Ok(());
+    }
+
+    Err(ParseError::new(
+        line_idx + 1,
+        1,
+        ParseErrorKind::Count {
+            items,
+            expected,
+            found,
+        },
+    ))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+enum Sign {
+    #[default]
+    Add,
+    Mul,
+}
+
+impl Sign {
+    fn from_char(ch: char) -> Option<Sign> {
+        match ch {
+            '+' => Some(Sign::Add),
+            '*' => Some(Sign::Mul),
+            _ => None,
+        }
+    }
+}
+
+impl FromStr for Sign {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut chars = s.chars();
+
+        let (expected, column, found) = match (chars.next(), chars.next()) {
+            (Some(ch), None) if let Some(sign) = Sign::from_char(ch) => return Ok(sign),
+            (Some(ch), Some(found)) if Sign::from_char(ch).is_some() => ("' '", 2, found),
+            (Some(found), _) => ("'+' or '*'", 1, found),
+            (None, _) => {
+                return Err(ParseError::new(
+                    1,
+                    1,
+                    ParseErrorKind::UnexpectedEnd {
+                        expected: "'+' or '*'",
+                    },
+                ));
+            }
+        };
+
+        Err(ParseError::new(
+            1,
+            column,
+            ParseErrorKind::UnexpectedChar { expected, found },
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day06;
+    use crate::{
+        Part, Solution,
+        parse::{ParseError, ParseErrorKind},
+        test_support::examples,
+    };
+
+    const TEST: &str = r#"123 328  51 64 
+ 45 64  387 23 
+  6 98  215 314
+*   +   *   +"#;
+
+    examples! {
+        Day06 {
+            TEST => part1: 4277556, part2: 3263827;
+        }
+    }
+
+    #[test]
+    fn test_overflow() {
+        let worksheet = Day06::parse("65535\n65535\n65535\n65535\n65535\n*").unwrap();
+        let error = format!("a result or the grand total is more than {}", usize::MAX);
+
+        assert_eq!(None, Day06::part1(&worksheet));
+        assert_eq!(
+            Err(error.clone()),
+            crate::AnySolution::solve(&Day06, &worksheet, Part::One)
+        );
+        assert_eq!(
+            Err(error),
+            crate::AnySolution::solve(&Day06, &worksheet, Part::Two)
+        );
+
+        let worksheet = Day06::parse("65535 65535\n65535 65535\n65535 65535\n*     +").unwrap();
+        assert_eq!(
+            Some(65535usize.pow(3) + 3 * 65535),
+            Day06::part1(&worksheet)
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            Err(ParseError::new(
+                2,
+                5,
+                ParseErrorKind::UnexpectedChar {
+                    expected: "digit",
+                    found: 'x'
+                }
+            )),
+            Day06::parse("1 2\n3 4 x5\n+ *").map(|_| ())
+        );
+        assert_eq!(
+            Err(ParseError::new(
+                3,
+                3,
+                ParseErrorKind::UnexpectedChar {
+                    expected: "'+' or '*'",
+                    found: '-'
+                }
+            )),
+            Day06::parse("1 2\n3 4\n+ -").map(|_| ())
+        );
+        assert_eq!(
+            Err(ParseError::new(
+                2,
+                1,
+                ParseErrorKind::Count {
+                    items: "numbers",
+                    expected: 2,
+                    found: 3
+                }
+            )),
+            Day06::parse("1 2\n3 4 5\n+ *").map(|_| ())
+        );
+        assert_eq!(
+            Err(ParseError::new(
+                3,
+                1,
+                ParseErrorKind::Missing {
+                    expected: "line of operation signs"
+                }
+            )),
+            Day06::parse("1 2\n3 4").map(|_| ())
+        );
+    }
+}
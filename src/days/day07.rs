@@ -0,0 +1,972 @@
+use crate::{
+    Part, Solution,
+    grid::{self, Cell, DIRECTIONS_4, Position},
+    parse::{ParseError, ParseErrorKind},
+    ratio::Ratio,
+    visualize::{Frame, Tile},
+};
+use std::{
+    collections::{BTreeSet, VecDeque},
+    io::{self, Write},
+    str::FromStr,
+};
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+
+    type Input = Manifold;
+    type Output = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input.parse()
+    }
+
+    fn part1(manifold: &Self::Input) -> Option<usize> {
+        Some(part1(manifold))
+    }
+
+    fn part2(manifold: &Self::Input) -> Option<usize> {
+        timelines(&manifold.grid, manifold.start)
+    }
+
+    fn unanswered(manifold: &Self::Input, part: Part) -> Option<String> {
+        match part {
+            Part::One => None,
+            Part::Two if manifold.has_optics() => Some(
+                "timelines are not counted through mirrors, directional splitters or absorbers"
+                    .to_string(),
+            ),
+            Part::Two if side_by_side(&manifold.grid) => {
+                Some("timelines are not counted with splitters side by side".to_string())
+            }
+            Part::Two => Some(format!("more than {} timelines", usize::MAX)),
+        }
+    }
+}
+
+/// The grid with its single start, which is always in the first row.
+#[derive(Debug, Clone)]
+pub struct Manifold {
+    grid: Grid,
+    start: Position,
+}
+
+impl FromStr for Manifold {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let grid: Grid = s.parse()?;
+
+        let starts: Vec<Position> = grid
+            .iter()
+            .filter(|(_, f)| **f == Field::Start)
+            .map(|(pos, _)| pos)
+            .collect();
+        let mut starts = starts.into_iter();
+
+        let start = starts.next().ok_or(ParseError::new(
+            1,
+            1,
+            ParseErrorKind::Missing {
+                expected: "start 'S' in the first row",
+            },
+        ))?;
+
+        let misplaced = if start.row != 0 {
+            Some(start)
+        } else {
+            starts.next()
+        };
+
+        if let Some(pos) = misplaced {
+            return Err(ParseError::new(
+                pos.row + 1,
+                pos.col + 1,
+                ParseErrorKind::UnexpectedChar {
+                    expected: "'.', '^', ':', '/', '\\', '-', '|' or '#'",
+                    found: 'S',
+                },
+            ));
+        }
+
+        Ok(Manifold { grid, start })
+    }
+}
+
+/// Where the beams from the start go, kept apart from the grid they go through.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BeamTrace {
+    /// Energised cells, the ones a beam went through, the start included.
+    pub visited: grid::Grid<bool>,
+    /// Splitters in the order beams split at them. A beam stops where it runs into another
+    /// going the same way, so a splitter only splits once per direction.
+    pub splits: Vec<Position>,
+    /// Columns where beams leave the bottom of the grid, in order. Beams leaving the other
+    /// sides are not listed.
+    pub exits: Vec<usize>,
+}
+
+impl BeamTrace {
+    /// Number of energised cells.
+    pub fn energised(&self) -> usize {
+        self.visited.iter().filter(|(_, visited)| **visited).count()
+    }
+
+    /// A copy of `grid` with the visited cells drawn as beams, which parses back to a manifold
+    /// with the same beams.
+    pub fn render(&self, grid: &Grid) -> Grid {
+        let mut rendered = grid.clone();
+
+        for (pos, _) in self.visited.iter().filter(|(_, visited)| **visited) {
+            if rendered[pos] == Field::Empty {
+                rendered[pos] = Field::Beam;
+            }
+        }
+
+        rendered
+    }
+}
+
+impl Manifold {
+    pub fn grid(&self) -> &Grid {
+        &self.grid
+    }
+
+    fn has_optics(&self) -> bool {
+        self.grid.iter().any(|(_, field)| field.is_optic())
+    }
+}
+
+/// Whether two splitters sit right next to each other in a row. A beam split at one of them
+/// runs into the other and is split again, in the same row, which the trace follows but
+/// counting row by row can't.
+fn side_by_side(grid: &Grid) -> bool {
+    grid.iter().any(|(pos, field)| {
+        *field == Field::Splitter
+            && pos
+                .offset(0, -1)
+                .is_some_and(|left| grid[left] == Field::Splitter)
+    })
+}
+
+impl Manifold {
+    /// Follows the beams from the start without touching the grid. Loops end where a beam
+    /// comes back to a cell in a direction it went through it before.
+    pub fn trace(&self) -> BeamTrace {
+        trace(&self.grid, self.start)
+    }
+}
+
+fn part1(manifold: &Manifold) -> usize {
+    manifold.trace().splits.len()
+}
+
+fn trace(grid: &Grid, start: Position) -> BeamTrace {
+    let mut visited = grid::Grid::filled(grid.width(), grid.height(), false);
+    let mut seen = grid::Grid::filled(grid.width(), grid.height(), 0);
+    let mut beams = vec![Beam {
+        pos: start,
+        dir: Direction::Down,
+    }];
+    let mut splits = Vec::new();
+    let mut exits = Vec::new();
+
+    while let Some(mut beam) = beams.pop() {
+        let result = beam.walk(grid, &mut visited, &mut seen);
+        match result.kind {
+            BeamResultKind::Split(new_beams) => {
+                splits.push(result.position);
+                beams.extend(new_beams.into_iter().flatten());
+            }
+            BeamResultKind::EndOfGrid if beam.dir == Direction::Down => {
+                exits.push(result.position.col);
+            }
+            BeamResultKind::EndOfGrid | BeamResultKind::Absorbed | BeamResultKind::BeamOverlap => {}
+        }
+    }
+
+    exits.sort_unstable();
+
+    BeamTrace {
+        visited,
+        splits,
+        exits,
+    }
+}
+
+/// Timelines that leave the bottom of the grid: every splitter sends a particle both ways,
+/// so each path from the start is one. Counts the timelines in each column row by row
+/// instead of following them. `None` if there are more than fit in a `usize`, if the grid
+/// has optics other than `^`, which can send particles back up, or splitters side by side.
+fn timelines(grid: &Grid, start: Position) -> Option<usize> {
+    propagate(grid, start, 1, usize::checked_add, |count| {
+        Some([count, count])
+    })?
+    .into_iter()
+    .try_fold(0, usize::checked_add)
+}
+
+impl Manifold {
+    /// Intensity of the light arriving at the bottom of each column, from a beam of
+    /// intensity 1 at the start, when every splitter sends the fraction `left` of a beam to
+    /// the left and the rest to the right. Light sent off the sides of the grid is lost.
+    ///
+    /// `None` if `left` is more than 1, if a fraction doesn't fit in a [`Ratio`], or if the
+    /// grid has optics other than `^` or splitters side by side.
+    pub fn intensities(&self, left: Ratio) -> Option<Vec<Ratio>> {
+        let right = Ratio::ONE.checked_sub(left)?;
+
+        propagate(
+            &self.grid,
+            self.start,
+            Ratio::ONE,
+            Ratio::checked_add,
+            |intensity| Some([intensity.checked_mul(left)?, intensity.checked_mul(right)?]),
+        )
+    }
+}
+
+/// Carries `amount` from the start down the grid row by row, with `split` dividing what
+/// reaches a splitter into what goes on to its left and to its right. What arrives at the
+/// bottom of each column, `None` if `add` or `split` fails, or if the grid has optics other
+/// than `^` or splitters side by side.
+fn propagate<T: Copy + Default + PartialEq>(
+    grid: &Grid,
+    start: Position,
+    amount: T,
+    add: impl Fn(T, T) -> Option<T>,
+    split: impl Fn(T) -> Option<[T; 2]>,
+) -> Option<Vec<T>> {
+    if grid.iter().any(|(_, field)| field.is_optic()) || side_by_side(grid) {
+        return None;
+    }
+
+    let mut amounts = vec![T::default(); grid.width()];
+    let mut next = vec![T::default(); grid.width()];
+    amounts[start.col] = amount;
+
+    for row in start.row + 1..grid.height() {
+        next.fill(T::default());
+
+        for (col, &amount) in amounts.iter().enumerate() {
+            if amount == T::default() {
+                continue;
+            }
+
+            let targets = match grid[Position::new(row, col)] {
+                Field::Splitter => {
+                    let [left, right] = split(amount)?;
+                    [
+                        col.checked_sub(1).map(|col| (col, left)),
+                        Some((col + 1, right)).filter(|&(col, _)| col < grid.width()),
+                    ]
+                }
+                _ => [Some((col, amount)), None],
+            };
+
+            for (target, amount) in targets.into_iter().flatten() {
+                next[target] = add(next[target], amount)?;
+            }
+        }
+
+        (amounts, next) = (next, amounts);
+    }
+
+    Some(amounts)
+}
+
+/// Where beams start, split or end.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Node {
+    Start,
+    Splitter(Position),
+    /// The bottom of a column.
+    Exit(usize),
+}
+
+impl Node {
+    /// Identifier in DOT.
+    fn id(self) -> String {
+        match self {
+            Node::Start => "start".to_string(),
+            Node::Splitter(pos) => format!("splitter_{}_{}", pos.row, pos.col),
+            Node::Exit(col) => format!("exit_{col}"),
+        }
+    }
+}
+
+/// A straight stretch of beam from one node down to the next.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Segment {
+    pub from: Node,
+    pub to: Node,
+    /// Rows the beam goes down.
+    pub length: usize,
+}
+
+/// The splitters the beams reach and how the beams connect them, with no cycles as beams
+/// only go down. Beams that merge end in the same node.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SplitterGraph {
+    /// Row by row.
+    pub splitters: Vec<Position>,
+    /// Columns where beams leave the bottom, in order.
+    pub exits: Vec<usize>,
+    /// In the order of the nodes they start at, the left one first.
+    pub segments: Vec<Segment>,
+}
+
+impl SplitterGraph {
+    /// The graph in Graphviz DOT, in a stable order so that graphs can be diffed.
+    pub fn write_dot(&self, mut w: impl Write) -> io::Result<()> {
+        writeln!(w, "digraph manifold {{")?;
+        writeln!(w, "    {} [label=\"S\"];", Node::Start.id())?;
+        for &pos in &self.splitters {
+            writeln!(
+                w,
+                "    {} [label=\"^ {pos}\", shape=triangle];",
+                Node::Splitter(pos).id()
+            )?;
+        }
+        for &col in &self.exits {
+            writeln!(
+                w,
+                "    {} [label=\"exit {col}\", shape=box];",
+                Node::Exit(col).id()
+            )?;
+        }
+
+        let exits: Vec<String> = self.exits.iter().map(|&col| Node::Exit(col).id()).collect();
+        if !exits.is_empty() {
+            writeln!(w, "    {{ rank=same; {}; }}", exits.join("; "))?;
+        }
+
+        for segment in &self.segments {
+            writeln!(
+                w,
+                "    {} -> {} [label=\"{}\"];",
+                segment.from.id(),
+                segment.to.id(),
+                segment.length
+            )?;
+        }
+
+        writeln!(w, "}}")
+    }
+}
+
+impl Manifold {
+    /// `None` if the grid has optics other than `^`, which can send beams in circles, or
+    /// splitters side by side, which send beams sideways from one to the other.
+    pub fn splitter_graph(&self) -> Option<SplitterGraph> {
+        if self.has_optics() || side_by_side(&self.grid) {
+            return None;
+        }
+
+        let grid = &self.grid;
+
+        // Where a beam going down from `pos` ends, always below it, and after how many rows.
+        let follow = |pos: Position| {
+            (pos.row + 1..grid.height())
+                .find(|&row| grid[Position::new(row, pos.col)] == Field::Splitter)
+                .map_or((Node::Exit(pos.col), grid.height() - pos.row), |row| {
+                    (Node::Splitter(Position::new(row, pos.col)), row - pos.row)
+                })
+        };
+
+        let mut splitters = BTreeSet::new();
+        let mut exits = BTreeSet::new();
+        let mut segments = Vec::new();
+        let mut beams = VecDeque::from([(Node::Start, self.start)]);
+
+        while let Some((from, pos)) = beams.pop_front() {
+            let (to, length) = follow(pos);
+            segments.push(Segment { from, to, length });
+
+            match to {
+                Node::Splitter(splitter) if splitters.insert(splitter) => {
+                    for side in [splitter.offset(0, -1), splitter.offset(0, 1)] {
+                        if let Some(side) = side.filter(|&side| grid.contains(side)) {
+                            beams.push_back((to, side));
+                        }
+                    }
+                }
+                Node::Exit(col) => {
+                    exits.insert(col);
+                }
+                Node::Start | Node::Splitter(_) => {}
+            }
+        }
+
+        // Stable, so the segments of a splitter stay left first.
+        segments.sort_by_key(|segment| segment.from);
+
+        Some(SplitterGraph {
+            splitters: splitters.into_iter().collect(),
+            exits: exits.into_iter().collect(),
+            segments,
+        })
+    }
+}
+
+/// Direction a beam travels in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    fn delta(self) -> (isize, isize) {
+        DIRECTIONS_4[self as usize]
+    }
+
+    /// Bit of the direction in a set of directions.
+    fn bit(self) -> u8 {
+        1 << self as u8
+    }
+
+    /// The two directions at right angles.
+    fn perpendicular(self) -> [Direction; 2] {
+        match self {
+            Direction::Up | Direction::Down => [Direction::Left, Direction::Right],
+            Direction::Left | Direction::Right => [Direction::Up, Direction::Down],
+        }
+    }
+
+    /// The direction after a `/` mirror.
+    fn slash(self) -> Direction {
+        match self {
+            Direction::Up => Direction::Right,
+            Direction::Right => Direction::Up,
+            Direction::Down => Direction::Left,
+            Direction::Left => Direction::Down,
+        }
+    }
+
+    /// The direction after a `\` mirror.
+    fn backslash(self) -> Direction {
+        match self {
+            Direction::Up => Direction::Left,
+            Direction::Left => Direction::Up,
+            Direction::Down => Direction::Right,
+            Direction::Right => Direction::Down,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Beam {
+    pos: Position,
+    dir: Direction,
+}
+
+impl Beam {
+    /// Moves until the beam splits, is absorbed, leaves the grid or goes where a beam went
+    /// the same way before, marking the cells it goes through in `visited` and the
+    /// directions it goes through them in `seen`.
+    fn walk(
+        &mut self,
+        grid: &Grid,
+        visited: &mut grid::Grid<bool>,
+        seen: &mut grid::Grid<u8>,
+    ) -> BeamResult {
+        loop {
+            let result = |kind| BeamResult {
+                position: self.pos,
+                kind,
+            };
+
+            if seen[self.pos] & self.dir.bit() != 0 {
+                return result(BeamResultKind::BeamOverlap);
+            }
+            seen[self.pos] |= self.dir.bit();
+            visited[self.pos] = true;
+
+            let split = |dirs: [Direction; 2]| {
+                BeamResultKind::Split(dirs.map(|dir| Some(Beam { pos: self.pos, dir })))
+            };
+
+            match (grid[self.pos], self.dir) {
+                (Field::Empty | Field::Start | Field::Beam, _) => {}
+                // Both new beams keep going the same way, one on each side of the splitter.
+                (Field::Splitter, dir) => {
+                    let sides = dir.perpendicular().map(|side| {
+                        let (d_row, d_col) = side.delta();
+                        let pos = self.pos.offset(d_row, d_col)?;
+                        grid.contains(pos).then_some(Beam { pos, dir })
+                    });
+                    return result(BeamResultKind::Split(sides));
+                }
+                (Field::SplitterHorizontal, Direction::Up | Direction::Down)
+                | (Field::SplitterVertical, Direction::Left | Direction::Right) => {
+                    return result(split(self.dir.perpendicular()));
+                }
+                (Field::SplitterHorizontal | Field::SplitterVertical, _) => {}
+                (Field::MirrorSlash, dir) => self.dir = dir.slash(),
+                (Field::MirrorBackslash, dir) => self.dir = dir.backslash(),
+                (Field::Absorber, _) => return result(BeamResultKind::Absorbed),
+            }
+
+            let (d_row, d_col) = self.dir.delta();
+            match self.pos.offset(d_row, d_col) {
+                Some(next_pos) if grid.contains(next_pos) => self.pos = next_pos,
+                _ => return result(BeamResultKind::EndOfGrid),
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct BeamResult {
+    /// Where the beam stopped.
+    position: Position,
+    kind: BeamResultKind,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum BeamResultKind {
+    /// The beams that go on, `None` where they would start outside the grid.
+    Split([Option<Beam>; 2]),
+    Absorbed,
+    EndOfGrid,
+    BeamOverlap,
+}
+
+pub type Grid = grid::Grid<Field>;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Field {
+    Empty,
+    Start,
+    /// `^`, sends a beam on both sides of it.
+    Splitter,
+    /// `:`, drawn by [`BeamTrace::render`] and passed through like an empty cell. Not `|` as
+    /// in the puzzle, which is a vertical splitter here.
+    Beam,
+    /// `/`
+    MirrorSlash,
+    /// `\`
+    MirrorBackslash,
+    /// `-`, splits vertical beams to the left and right and lets horizontal ones through.
+    SplitterHorizontal,
+    /// `|`, splits horizontal beams up and down and lets vertical ones through.
+    SplitterVertical,
+    /// `#`, stops beams.
+    Absorber,
+}
+
+impl Field {
+    /// Not part of the puzzle, only of the sandbox around it.
+    fn is_optic(self) -> bool {
+        matches!(
+            self,
+            Field::MirrorSlash
+                | Field::MirrorBackslash
+                | Field::SplitterHorizontal
+                | Field::SplitterVertical
+                | Field::Absorber
+        )
+    }
+}
+
+impl Cell for Field {
+    const EXPECTED: &'static str = "'.', 'S', '^', ':', '/', '\\', '-', '|' or '#'";
+
+    fn from_char(ch: char) -> Option<Self> {
+        match ch {
+            '.' => Some(Field::Empty),
+            'S' => Some(Field::Start),
+            '^' => Some(Field::Splitter),
+            ':' => Some(Field::Beam),
+            '/' => Some(Field::MirrorSlash),
+            '\\' => Some(Field::MirrorBackslash),
+            '-' => Some(Field::SplitterHorizontal),
+            '|' => Some(Field::SplitterVertical),
+            '#' => Some(Field::Absorber),
+            _ => None,
+        }
+    }
+
+    fn to_char(&self) -> char {
+        match self {
+            Field::Empty => '.',
+            Field::Start => 'S',
+            Field::Splitter => '^',
+            Field::Beam => ':',
+            Field::MirrorSlash => '/',
+            Field::MirrorBackslash => '\\',
+            Field::SplitterHorizontal => '-',
+            Field::SplitterVertical => '|',
+            Field::Absorber => '#',
+        }
+    }
+}
+
+/// The manifold as the beams advance by one row per frame. Beams only go down, like in the
+/// puzzle, and stop at mirrors, directional splitters and absorbers.
+pub fn beam_frames(manifold: &Manifold) -> Vec<Frame> {
+    let grid = &manifold.grid;
+    let mut tiles = grid.map(|field| match field {
+        Field::Empty => Tile::Empty,
+        Field::Start => Tile::Start,
+        Field::Splitter => Tile::Splitter,
+        Field::Beam => Tile::Beam,
+        optic => Tile::Optic(optic.to_char()),
+    });
+
+    let mut beams = vec![false; grid.width()];
+    beams[manifold.start.col] = true;
+    let mut splits = 0;
+    let mut frames = vec![Frame {
+        caption: format!("row {}: 0 splits", manifold.start.row),
+        tiles: tiles.clone(),
+    }];
+
+    for row in manifold.start.row + 1..grid.height() {
+        let mut next = vec![false; grid.width()];
+
+        for col in (0..grid.width()).filter(|&col| beams[col]) {
+            let pos = Position::new(row, col);
+
+            if grid[pos] == Field::Splitter {
+                splits += 1;
+                tiles[pos] = Tile::SplitterHit;
+                if col > 0 {
+                    next[col - 1] = true;
+                }
+                if col + 1 < grid.width() {
+                    next[col + 1] = true;
+                }
+            } else if !grid[pos].is_optic() {
+                next[col] = true;
+            }
+        }
+        beams = next;
+
+        for tile in tiles.row_mut(row - 1).into_iter().flatten() {
+            if *tile == Tile::BeamFront {
+                *tile = Tile::Beam;
+            }
+        }
+        for col in (0..grid.width()).filter(|&col| beams[col]) {
+            tiles[Position::new(row, col)] = Tile::BeamFront;
+        }
+
+        frames.push(Frame {
+            caption: format!("row {row}: {splits} splits"),
+            tiles: tiles.clone(),
+        });
+    }
+
+    frames
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Day07, Grid, Manifold, Node, beam_frames, timelines};
+    use crate::{
+        AnySolution, Part,
+        grid::Position,
+        parse::{ParseError, ParseErrorKind},
+        ratio::Ratio,
+        test_support::examples,
+        visualize::Tile,
+    };
+
+    const TEST: &str = r#".......S.......
+...............
+.......^.......
+...............
+......^.^......
+...............
+.....^.^.^.....
+...............
+....^.^...^....
+...............
+...^.^...^.^...
+...............
+..^...^.....^..
+...............
+.^.^.^.^.^...^.
+..............."#;
+
+    examples! {
+        Day07 {
+            TEST => part1: 21, part2: 40;
+        }
+    }
+
+    /// A splitter on every reachable cell of `levels` levels, so every path doubles.
+    fn pyramid(levels: usize) -> Manifold {
+        let width = 2 * levels + 1;
+        let mut lines = vec![format!("{:^width$}", "S").replace(' ', ".")];
+
+        for level in 0..levels {
+            lines.push(".".repeat(width));
+            lines.push(
+                (0..width)
+                    .map(|col| {
+                        let reachable = col.abs_diff(levels) <= level;
+                        match reachable && (col + levels + level).is_multiple_of(2) {
+                            true => '^',
+                            false => '.',
+                        }
+                    })
+                    .collect(),
+            );
+        }
+
+        lines.join("\n").parse().unwrap()
+    }
+
+    #[test]
+    fn test_timelines() {
+        let manifold = pyramid(3);
+        assert_eq!(Some(8), timelines(&manifold.grid, manifold.start));
+
+        let manifold = pyramid(usize::BITS as usize - 1);
+        assert_eq!(
+            Some(1 << (usize::BITS - 1)),
+            timelines(&manifold.grid, manifold.start)
+        );
+
+        let manifold = pyramid(usize::BITS as usize);
+        assert_eq!(None, timelines(&manifold.grid, manifold.start));
+        assert_eq!(
+            Err(format!("more than {} timelines", usize::MAX)),
+            Day07.solve(&manifold, Part::Two)
+        );
+    }
+
+    #[test]
+    fn test_trace() {
+        let manifold: Manifold = ".S.\n...\n.^.\n...".parse().unwrap();
+        let trace = manifold.trace();
+
+        assert_eq!(vec![Position::new(2, 1)], trace.splits);
+        assert_eq!(vec![0, 2], trace.exits);
+        assert_eq!(
+            ".S.\n.:.\n:^:\n:.:",
+            trace.render(manifold.grid()).to_string()
+        );
+        assert_eq!(".S.\n...\n.^.\n...", manifold.grid().to_string());
+
+        let manifold: Manifold = TEST.parse().unwrap();
+        let trace = manifold.trace();
+
+        assert_eq!(21, trace.splits.len());
+        assert_eq!(vec![0, 2, 4, 6, 8, 10, 11, 12, 14], trace.exits);
+        assert_eq!(trace, manifold.trace());
+    }
+
+    #[test]
+    fn test_trace_optics() {
+        // The split beams go round the mirrors both ways until they meet their own trail.
+        let manifold: Manifold = r".S..
+/-.\
+....
+\../"
+            .parse()
+            .unwrap();
+        let trace = manifold.trace();
+
+        assert_eq!(11, trace.energised());
+        assert_eq!(vec![Position::new(1, 1)], trace.splits);
+        assert_eq!(Vec::<usize>::new(), trace.exits);
+        assert_eq!(None, timelines(&manifold.grid, manifold.start));
+        assert_eq!(
+            Err(
+                "timelines are not counted through mirrors, directional splitters or absorbers"
+                    .to_string()
+            ),
+            Day07.solve(&manifold, Part::Two)
+        );
+
+        // The absorber stops the beam that the vertical splitter sends up.
+        let manifold: Manifold = "S.#.\n\\.|.\n....\n....".parse().unwrap();
+        let trace = manifold.trace();
+
+        assert_eq!(7, trace.energised());
+        assert_eq!(vec![Position::new(1, 2)], trace.splits);
+        assert_eq!(vec![2], trace.exits);
+        assert_eq!(
+            "S.#.\n\\:|.\n..:.\n..:.",
+            trace.render(manifold.grid()).to_string()
+        );
+
+        let rendered: Manifold = trace.render(manifold.grid()).to_string().parse().unwrap();
+        assert_eq!(trace, rendered.trace());
+    }
+
+    #[test]
+    fn test_intensities() {
+        let ratios = |ratios: [&str; 5]| Some(ratios.map(|r| r.parse().unwrap()).to_vec());
+        let manifold = pyramid(2);
+
+        assert_eq!(
+            ratios(["1/4", "0", "1/2", "0", "1/4"]),
+            manifold.intensities(Ratio::HALF)
+        );
+        assert_eq!(
+            ratios(["1/9", "0", "4/9", "0", "4/9"]),
+            manifold.intensities(Ratio::new(1, 3).unwrap())
+        );
+        assert_eq!(None, manifold.intensities(Ratio::new(3, 2).unwrap()));
+
+        // Light reaches the bottom where the trace's beams do, also with splitters close by.
+        let manifold: Manifold = ".S...\n.....\n.^.^.\n..^..\n.....".parse().unwrap();
+        let intensities = manifold.intensities(Ratio::HALF).unwrap();
+        assert_eq!(
+            ratios(["1/2", "1/4", "0", "1/4", "0"]),
+            Some(intensities.clone())
+        );
+        assert_eq!(
+            manifold.trace().exits,
+            (0..intensities.len())
+                .filter(|&col| intensities[col] != Ratio::ZERO)
+                .collect::<Vec<_>>()
+        );
+
+        let manifold: Manifold = TEST.parse().unwrap();
+        let total = manifold
+            .intensities(Ratio::HALF)
+            .unwrap()
+            .into_iter()
+            .try_fold(Ratio::ZERO, Ratio::checked_add);
+        assert_eq!(Some(Ratio::ONE), total);
+    }
+
+    #[test]
+    fn test_splitter_graph() {
+        let manifold: Manifold = ".S.\n...\n.^.\n...".parse().unwrap();
+        let mut dot = Vec::new();
+        manifold
+            .splitter_graph()
+            .unwrap()
+            .write_dot(&mut dot)
+            .unwrap();
+
+        assert_eq!(
+            r#"digraph manifold {
+    start [label="S"];
+    splitter_2_1 [label="^ (2,1)", shape=triangle];
+    exit_0 [label="exit 0", shape=box];
+    exit_2 [label="exit 2", shape=box];
+    { rank=same; exit_0; exit_2; }
+    start -> splitter_2_1 [label="2"];
+    splitter_2_1 -> exit_0 [label="2"];
+    splitter_2_1 -> exit_2 [label="2"];
+}
+"#,
+            String::from_utf8(dot).unwrap()
+        );
+
+        // The beams between the splitters of the second level merge.
+        let graph = pyramid(2).splitter_graph().unwrap();
+        assert_eq!(vec![0, 2, 4], graph.exits);
+        assert_eq!(7, graph.segments.len());
+        assert_eq!(
+            2,
+            graph
+                .segments
+                .iter()
+                .filter(|segment| segment.to == Node::Exit(2))
+                .count()
+        );
+
+        let manifold: Manifold = TEST.parse().unwrap();
+        let graph = manifold.splitter_graph().unwrap();
+        assert_eq!(21, graph.splitters.len());
+        assert_eq!(manifold.trace().exits, graph.exits);
+
+        // Every segment goes down, also between splitters close to each other.
+        let manifold: Manifold = ".S...\n.....\n.^.^.\n..^..\n.....".parse().unwrap();
+        let graph = manifold.splitter_graph().unwrap();
+        assert_eq!(manifold.trace().exits, graph.exits);
+        assert!(graph.segments.iter().all(|segment| {
+            let row = |node| match node {
+                Node::Start => 0,
+                Node::Splitter(pos) => pos.row,
+                Node::Exit(_) => manifold.grid().height(),
+            };
+            row(segment.from) < row(segment.to)
+        }));
+
+        let manifold: Manifold = ".S.\n./.".parse().unwrap();
+        assert_eq!(None, manifold.splitter_graph());
+    }
+
+    #[test]
+    fn test_beam_frames() {
+        let frames = beam_frames(&TEST.parse().unwrap());
+        let last = frames.last().unwrap();
+
+        assert_eq!(16, frames.len());
+        assert_eq!("row 15: 21 splits", last.caption);
+        assert_eq!(
+            ".......S.......\n\
+             .......:.......\n\
+             ......:^:......",
+            frames[2]
+                .tiles
+                .rows()
+                .take(3)
+                .map(|row| row.iter().map(|tile| tile.glyph()).collect::<String>())
+                .collect::<Vec<_>>()
+                .join("\n")
+        );
+        assert_eq!(Tile::BeamFront, frames[2].tiles[Position::new(2, 6)]);
+        assert_eq!(Tile::Beam, frames[3].tiles[Position::new(2, 6)]);
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(TEST, TEST.parse::<Grid>().unwrap().to_string().as_str())
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            Err(ParseError::new(
+                1,
+                1,
+                ParseErrorKind::Missing {
+                    expected: "start 'S' in the first row"
+                }
+            )),
+            "...\n.^.".parse::<Manifold>().map(|_| ())
+        );
+        assert_eq!(
+            Err(ParseError::new(
+                2,
+                3,
+                ParseErrorKind::UnexpectedChar {
+                    expected: "'.', '^', ':', '/', '\\', '-', '|' or '#'",
+                    found: 'S'
+                }
+            )),
+            ".S.\n.^S".parse::<Manifold>().map(|_| ())
+        );
+        assert!(".S..\n....\n.^.^\n..^.".parse::<Manifold>().is_ok());
+    }
+
+    #[test]
+    fn test_side_by_side() {
+        // The beam split to the right runs into the second splitter and is split again, the
+        // part going back left stopping where the first beam went down before.
+        let manifold: Manifold = ".S..\n....\n.^^.\n....".parse().unwrap();
+        assert_eq!(vec![0, 3], manifold.trace().exits);
+        assert_eq!(Ok(Some("2".to_string())), Day07.solve(&manifold, Part::One));
+        assert_eq!(
+            Err("timelines are not counted with splitters side by side".to_string()),
+            Day07.solve(&manifold, Part::Two)
+        );
+        assert_eq!(None, manifold.intensities(Ratio::HALF));
+        assert_eq!(None, manifold.splitter_graph());
+    }
+}
@@ -0,0 +1,24 @@
+use crate::AnySolution;
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+
+/// Every solved day, in order.
+pub const ALL: [&dyn AnySolution; 7] = [
+    &day01::Day01,
+    &day02::Day02,
+    &day03::Day03,
+    &day04::Day04,
+    &day05::Day05,
+    &day06::Day06,
+    &day07::Day07,
+];
+
+pub fn get(day: u8) -> Option<&'static dyn AnySolution> {
+    ALL.into_iter().find(|solution| solution.day() == day)
+}
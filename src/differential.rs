@@ -0,0 +1,319 @@
+use crate::{
+    AnySolution, Part,
+    generate::{self, DEFAULT_SIZE},
+    reference,
+    report::panic_message,
+};
+use std::{
+    collections::BTreeSet,
+    fmt::Display,
+    panic::{self, AssertUnwindSafe},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DiffOptions {
+    /// Number of generated inputs.
+    pub cases: usize,
+    /// Seed of the first input. Every following input uses the next seed.
+    pub seed: u64,
+    /// Largest size passed to the generator. Sizes cycle from 1 up to it.
+    pub size: usize,
+}
+
+impl Default for DiffOptions {
+    fn default() -> Self {
+        DiffOptions {
+            cases: 1000,
+            seed: 0,
+            size: DEFAULT_SIZE,
+        }
+    }
+}
+
+/// An input on which a solution and its reference disagree.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mismatch {
+    pub day: u8,
+    pub part: Part,
+    /// Seed of the generated input before it was shrunk.
+    pub seed: u64,
+    /// The smallest input found that still disagrees.
+    pub input: String,
+    pub expected: String,
+    /// The error if the solution failed or panicked.
+    pub answer: Result<String, String>,
+}
+
+impl Display for Mismatch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "day {:02} part {} disagrees with the reference on seed {}",
+            self.day, self.part, self.seed
+        )?;
+        writeln!(f, "expected: {}", self.expected)?;
+        match &self.answer {
+            Ok(answer) => writeln!(f, "answer: {answer}")?,
+            Err(err) => writeln!(f, "error: {err}")?,
+        }
+        write!(f, "shrunk input:\n{}", self.input)
+    }
+}
+
+/// Compares `solution` with its [`reference`] on generated inputs and shrinks the first
+/// disagreement to a minimal input.
+///
+/// Returns how many answers were compared. Unsolved parts and inputs without a reference
+/// answer are skipped.
+pub fn check(solution: &dyn AnySolution, options: DiffOptions) -> Result<usize, Mismatch> {
+    let day = solution.day();
+    let mut compared = 0;
+
+    for i in 0..options.cases {
+        let seed = options.seed.wrapping_add(i as u64);
+        let size = 1 + i % options.size.max(1);
+        let Some(input) = generate::generate_small(day, seed, size) else {
+            break;
+        };
+
+        for part in Part::BOTH {
+            let Some(expected) = reference::solve(day, &input, part) else {
+                continue;
+            };
+
+            match answer(solution, &input, part) {
+                Ok(Some(answer)) if answer == expected => compared += 1,
+                Ok(None) => {}
+                _ => {
+                    let input = shrink(&input, |candidate| disagrees(solution, candidate, part));
+
+                    return Err(Mismatch {
+                        day,
+                        part,
+                        seed,
+                        expected: reference::solve(day, &input, part).unwrap_or_default(),
+                        answer: answer(solution, &input, part).map(Option::unwrap_or_default),
+                        input,
+                    });
+                }
+            }
+        }
+    }
+
+    Ok(compared)
+}
+
+fn answer(solution: &dyn AnySolution, input: &str, part: Part) -> Result<Option<String>, String> {
+    panic::catch_unwind(AssertUnwindSafe(|| {
+        let input = solution.parse(input).map_err(|err| err.to_string())?;
+        solution.solve(input.as_ref(), part)
+    }))
+    .unwrap_or_else(|payload| Err(format!("panicked: {}", panic_message(payload.as_ref()))))
+}
+
+/// The reference has an answer and the solution a different one, or an error.
+fn disagrees(solution: &dyn AnySolution, input: &str, part: Part) -> bool {
+    let Some(expected) = reference::solve(solution.day(), input, part) else {
+        return false;
+    };
+
+    match answer(solution, input, part) {
+        Ok(Some(answer)) => answer != expected,
+        Ok(None) => false,
+        Err(_) => true,
+    }
+}
+
+/// Makes `input` smaller while `fails` holds for it: drops lines, columns, comma separated
+/// items and characters, and lowers numbers, until no single step keeps it failing.
+pub fn shrink(input: &str, mut fails: impl FnMut(&str) -> bool) -> String {
+    let mut current = input.to_string();
+
+    'smaller: loop {
+        for candidate in candidates(&current) {
+            if fails(&candidate) {
+                current = candidate;
+                continue 'smaller;
+            }
+        }
+
+        return current;
+    }
+}
+
+/// Every input one step smaller than `input`, the largest steps first. Each is shorter, or
+/// as long with a lower number, so shrinking always ends.
+fn candidates(input: &str) -> Vec<String> {
+    let lines: Vec<&str> = input.lines().collect();
+    let width = lines
+        .iter()
+        .map(|line| line.chars().count())
+        .max()
+        .unwrap_or(0);
+    let mut candidates = Vec::new();
+
+    for i in 0..lines.len() {
+        let mut fewer = lines.clone();
+        fewer.remove(i);
+        candidates.push(fewer.join("\n"));
+    }
+
+    for col in 0..width {
+        let narrower: Vec<String> = lines
+            .iter()
+            .map(|line| {
+                line.chars()
+                    .enumerate()
+                    .filter(|&(c, _)| c != col)
+                    .map(|(_, ch)| ch)
+                    .collect()
+            })
+            .collect();
+        candidates.push(narrower.join("\n"));
+    }
+
+    for (i, line) in lines.iter().enumerate() {
+        let items: Vec<&str> = line.split(',').collect();
+        if items.len() < 2 {
+            continue;
+        }
+
+        for j in 0..items.len() {
+            let mut fewer = items.clone();
+            fewer.remove(j);
+            candidates.push(replace_line(&lines, i, &fewer.join(",")));
+        }
+    }
+
+    for (i, line) in lines.iter().enumerate() {
+        for (at, ch) in line.char_indices() {
+            let shorter = format!("{}{}", &line[..at], &line[at + ch.len_utf8()..]);
+            candidates.push(replace_line(&lines, i, &shorter));
+        }
+    }
+
+    for (i, line) in lines.iter().enumerate() {
+        for (start, digits) in numbers(line) {
+            let Ok(number) = digits.parse::<u64>() else {
+                continue;
+            };
+
+            for lower in [number / 2, number.saturating_sub(1)] {
+                if lower < number {
+                    let end = start + digits.len();
+                    let lowered = format!("{}{lower}{}", &line[..start], &line[end..]);
+                    candidates.push(replace_line(&lines, i, &lowered));
+                }
+            }
+        }
+    }
+
+    let mut seen = BTreeSet::new();
+    candidates.retain(|candidate| candidate != input && seen.insert(candidate.clone()));
+    candidates
+}
+
+fn replace_line(lines: &[&str], i: usize, line: &str) -> String {
+    let mut lines = lines.to_vec();
+    lines[i] = line;
+    lines.join("\n")
+}
+
+/// Runs of ASCII digits with their byte offsets.
+fn numbers(line: &str) -> Vec<(usize, &str)> {
+    let mut numbers = Vec::new();
+    let mut start = None;
+
+    for (i, ch) in line.char_indices().chain([(line.len(), ' ')]) {
+        match (ch.is_ascii_digit(), start) {
+            (true, None) => start = Some(i),
+            (false, Some(s)) => {
+                numbers.push((s, &line[s..i]));
+                start = None;
+            }
+            _ => {}
+        }
+    }
+
+    numbers
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DiffOptions, check, numbers, shrink};
+    use crate::{Part, Solution, days, parse::ParseError};
+
+    #[test]
+    fn test_numbers() {
+        assert_eq!(vec![(0, "12"), (3, "345"), (8, "6")], numbers("12-345, 6"));
+    }
+
+    #[test]
+    fn test_shrink() {
+        let large = |input: &str| {
+            input
+                .split(|ch: char| !ch.is_ascii_digit())
+                .any(|number| number.parse::<u64>().is_ok_and(|n| n >= 7))
+        };
+
+        assert_eq!("7", shrink("1,2\n30,4\n5", large));
+        assert_eq!("ok", shrink("ok", large));
+    }
+
+    #[test]
+    fn test_check() {
+        let options = DiffOptions {
+            cases: 100,
+            ..DiffOptions::default()
+        };
+
+        for solution in days::ALL {
+            let compared = check(solution, options).unwrap_or_else(|mismatch| panic!("{mismatch}"));
+            assert!(compared >= options.cases, "day {}", solution.day());
+        }
+    }
+
+    /// Day 01 with rotations off by one click.
+    struct Broken;
+
+    impl Solution for Broken {
+        const DAY: u8 = 1;
+
+        type Input = Vec<String>;
+        type Output = usize;
+
+        fn parse(input: &str) -> Result<Self::Input, ParseError> {
+            Ok(input.lines().map(str::to_string).collect())
+        }
+
+        fn part1(rotations: &Self::Input) -> Option<usize> {
+            let mut point = 50;
+            let zeros = rotations.iter().filter(|rotation| {
+                let clicks: i64 = rotation[1..].parse().unwrap();
+                let clicks = if rotation.starts_with('L') {
+                    -clicks
+                } else {
+                    clicks
+                };
+                point = (point + clicks + 1).rem_euclid(100);
+                point == 0
+            });
+
+            Some(zeros.count())
+        }
+
+        fn part2(_: &Self::Input) -> Option<usize> {
+            None
+        }
+    }
+
+    #[test]
+    fn test_check_mismatch() {
+        let mismatch = check(&Broken, DiffOptions::default()).unwrap_err();
+
+        assert_eq!(Part::One, mismatch.part);
+        // One rotation that ends on 0 for exactly one of them.
+        assert_eq!(1, mismatch.input.lines().count(), "{mismatch}");
+        assert_ne!(Ok(mismatch.expected.clone()), mismatch.answer);
+    }
+}
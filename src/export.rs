@@ -0,0 +1,769 @@
+use crate::{
+    parse::{ParseError, ParseErrorKind, split_with_columns},
+    visualize::{Frame, Tile},
+};
+use std::{
+    fs,
+    io::{self, Write},
+    iter,
+    path::{Path, PathBuf},
+    str::FromStr,
+    time::Duration,
+};
+
+type Rgb = [u8; 3];
+
+/// Names of the palette entries, in the order of their indices.
+const NAMES: [&str; 10] = [
+    "empty",
+    "paper",
+    "fading0",
+    "fading1",
+    "fading2",
+    "start",
+    "splitter",
+    "splitter-hit",
+    "beam",
+    "beam-front",
+];
+
+/// The colour of every [`Tile`]. Parsed from `name=#rrggbb` entries separated by commas, which
+/// override the defaults, e.g. `beam=#ffcc00,empty=#000000`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Palette {
+    colours: [Rgb; NAMES.len()],
+}
+
+impl Default for Palette {
+    fn default() -> Self {
+        Palette {
+            colours: [
+                [15, 15, 35],
+                [230, 230, 230],
+                [255, 80, 80],
+                [170, 50, 50],
+                [90, 30, 40],
+                [80, 220, 100],
+                [60, 160, 200],
+                [120, 230, 255],
+                [200, 170, 40],
+                [255, 240, 120],
+            ],
+        }
+    }
+}
+
+impl Palette {
+    fn index(tile: Tile) -> u8 {
+        match tile {
+            Tile::Empty => 0,
+            Tile::Paper => 1,
+            Tile::Fading(age) => 2 + age.min(2),
+            Tile::Start => 5,
+            Tile::Splitter | Tile::Optic(_) => 6,
+            Tile::SplitterHit => 7,
+            Tile::Beam => 8,
+            Tile::BeamFront => 9,
+        }
+    }
+
+    fn rgb(&self, index: u8) -> Rgb {
+        self.colours[index as usize]
+    }
+}
+
+impl FromStr for Palette {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut palette = Palette::default();
+
+        for (column, entry) in split_with_columns(s, |ch| ch == ',') {
+            let err = |offset: usize, kind| ParseError::new(1, column + offset + 1, kind);
+
+            let (name, colour) = entry.split_once('=').ok_or(err(
+                entry.len(),
+                ParseErrorKind::UnexpectedEnd { expected: "'='" },
+            ))?;
+            let index = NAMES.iter().position(|n| *n == name.trim()).ok_or(err(
+                0,
+                ParseErrorKind::UnexpectedChar {
+                    expected: "tile name such as empty, paper or beam",
+                    found: name.chars().next().unwrap_or('='),
+                },
+            ))?;
+
+            palette.colours[index] =
+                parse_colour(colour).map_err(|e| e.shift(0, column + name.len() + 1))?;
+        }
+
+        Ok(palette)
+    }
+}
+
+/// `#rrggbb`
+fn parse_colour(s: &str) -> Result<Rgb, ParseError> {
+    let unexpected = |column: usize, expected, found| {
+        ParseError::new(
+            1,
+            column + 1,
+            ParseErrorKind::UnexpectedChar { expected, found },
+        )
+    };
+
+    let mut chars = s.chars();
+    match chars.next() {
+        Some('#') => {}
+        Some(ch) => return Err(unexpected(0, "'#'", ch)),
+        None => {
+            return Err(ParseError::new(
+                1,
+                1,
+                ParseErrorKind::UnexpectedEnd { expected: "'#'" },
+            ));
+        }
+    }
+
+    let mut rgb = [0; 3];
+    for (i, ch) in chars.enumerate() {
+        let digit = ch.to_digit(16).ok_or(unexpected(i + 1, "hex digit", ch))?;
+        let channel = rgb
+            .get_mut(i / 2)
+            .ok_or(unexpected(i + 1, "end of colour", ch))?;
+        *channel = *channel * 16 + digit as u8;
+    }
+
+    match s.len() {
+        7 => Ok(rgb),
+        len => Err(ParseError::new(
+            1,
+            len + 1,
+            ParseErrorKind::UnexpectedEnd {
+                expected: "hex digit",
+            },
+        )),
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ImageFormat {
+    Ppm,
+    #[default]
+    Png,
+}
+
+impl FromStr for ImageFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "ppm" => Ok(ImageFormat::Ppm),
+            "png" => Ok(ImageFormat::Png),
+            _ => Err(format!("invalid image format: {s}, expected ppm or png")),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExportOptions {
+    /// Side of a grid cell in pixels.
+    pub cell: usize,
+    pub palette: Palette,
+    pub format: ImageFormat,
+    /// Frame delay of an animated GIF of all frames, none if `None`.
+    pub gif: Option<Duration>,
+}
+
+impl Default for ExportOptions {
+    fn default() -> Self {
+        ExportOptions {
+            cell: 4,
+            palette: Palette::default(),
+            format: ImageFormat::default(),
+            gif: None,
+        }
+    }
+}
+
+/// Writes `frame-0001.png` and so on, and `animation.gif` if asked for, into `dir`.
+pub fn export(frames: &[Frame], options: &ExportOptions, dir: &Path) -> io::Result<Vec<PathBuf>> {
+    fs::create_dir_all(dir)?;
+
+    let images: Vec<Image> = frames
+        .iter()
+        .map(|frame| Image::render(frame, options.cell))
+        .collect();
+    let mut paths = Vec::new();
+
+    for (i, image) in images.iter().enumerate() {
+        let (extension, data) = match options.format {
+            ImageFormat::Ppm => ("ppm", image.ppm(&options.palette)),
+            ImageFormat::Png => ("png", image.png(&options.palette)?),
+        };
+
+        let path = dir.join(format!("frame-{:04}.{extension}", i + 1));
+        fs::write(&path, data)?;
+        paths.push(path);
+    }
+
+    if let Some(delay) = options.gif {
+        let data = gif(&images, &options.palette, delay)?;
+        let path = dir.join("animation.gif");
+        let mut file = io::BufWriter::new(fs::File::create(&path)?);
+        file.write_all(&data)?;
+        file.flush()?;
+        paths.push(path);
+    }
+
+    Ok(paths)
+}
+
+/// Pixels as palette indices.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Image {
+    width: usize,
+    height: usize,
+    pixels: Vec<u8>,
+}
+
+impl Image {
+    fn render(frame: &Frame, cell: usize) -> Image {
+        let width = frame.tiles.width() * cell;
+        let height = frame.tiles.height() * cell;
+        let mut pixels = Vec::with_capacity(width * height);
+
+        for row in frame.tiles.rows() {
+            let line: Vec<u8> = row
+                .iter()
+                .flat_map(|&tile| iter::repeat_n(Palette::index(tile), cell))
+                .collect();
+
+            for _ in 0..cell {
+                pixels.extend_from_slice(&line);
+            }
+        }
+
+        Image {
+            width,
+            height,
+            pixels,
+        }
+    }
+
+    /// Binary `P6` PPM.
+    fn ppm(&self, palette: &Palette) -> Vec<u8> {
+        let mut data = format!("P6\n{} {}\n255\n", self.width, self.height).into_bytes();
+        data.extend(self.pixels.iter().flat_map(|&index| palette.rgb(index)));
+        data
+    }
+
+    /// 8-bit indexed PNG.
+    fn png(&self, palette: &Palette) -> io::Result<Vec<u8>> {
+        let mut data = b"\x89PNG\r\n\x1a\n".to_vec();
+
+        let mut header = Vec::new();
+        header.extend_from_slice(&side::<u32>(self.width, "PNG")?.to_be_bytes());
+        header.extend_from_slice(&side::<u32>(self.height, "PNG")?.to_be_bytes());
+        // Bit depth 8, indexed colour, deflate, adaptive filtering, no interlace.
+        header.extend_from_slice(&[8, 3, 0, 0, 0]);
+        png_chunk(&mut data, b"IHDR", &header);
+
+        let colours: Vec<u8> = palette.colours.iter().flatten().copied().collect();
+        png_chunk(&mut data, b"PLTE", &colours);
+
+        // Every line starts with filter type 0, none.
+        let mut raw = Vec::with_capacity((self.width + 1) * self.height);
+        for line in self.pixels.chunks(self.width.max(1)) {
+            raw.push(0);
+            raw.extend_from_slice(line);
+        }
+        png_chunk(&mut data, b"IDAT", &zlib(&raw));
+
+        png_chunk(&mut data, b"IEND", &[]);
+        Ok(data)
+    }
+}
+
+/// Width or height of an image as the type a format stores it in, an error if it's too large.
+fn side<T: TryFrom<usize>>(pixels: usize, format: &str) -> io::Result<T> {
+    T::try_from(pixels).map_err(|_| {
+        io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("{pixels} pixels is too wide or tall for a {format}, use a smaller --cell"),
+        )
+    })
+}
+
+fn png_chunk(data: &mut Vec<u8>, kind: &[u8; 4], content: &[u8]) {
+    data.extend_from_slice(&(content.len() as u32).to_be_bytes());
+    let start = data.len();
+    data.extend_from_slice(kind);
+    data.extend_from_slice(content);
+    let crc = crc32(&data[start..]);
+    data.extend_from_slice(&crc.to_be_bytes());
+}
+
+fn crc32(data: &[u8]) -> u32 {
+    let mut crc = !0u32;
+
+    for &byte in data {
+        crc ^= byte as u32;
+        for _ in 0..8 {
+            crc = if crc & 1 == 1 {
+                (crc >> 1) ^ 0xedb8_8320
+            } else {
+                crc >> 1
+            };
+        }
+    }
+
+    !crc
+}
+
+fn adler32(data: &[u8]) -> u32 {
+    let (mut a, mut b) = (1u32, 0u32);
+
+    for &byte in data {
+        a = (a + byte as u32) % 65521;
+        b = (b + a) % 65521;
+    }
+
+    (b << 16) | a
+}
+
+fn zlib(data: &[u8]) -> Vec<u8> {
+    // Deflate with a 32 KiB window, no preset dictionary.
+    let mut out = vec![0x78, 0x01];
+    out.extend(deflate(data));
+    out.extend_from_slice(&adler32(data).to_be_bytes());
+    out
+}
+
+/// Bits packed from the least significant bit up, as deflate and GIF want them.
+#[derive(Default)]
+struct BitWriter {
+    bytes: Vec<u8>,
+    bits: u32,
+    len: u32,
+}
+
+impl BitWriter {
+    fn write(&mut self, value: u32, len: u32) {
+        self.bits |= value << self.len;
+        self.len += len;
+
+        while self.len >= 8 {
+            self.bytes.push(self.bits as u8);
+            self.bits >>= 8;
+            self.len -= 8;
+        }
+    }
+
+    /// Huffman codes are stored from the most significant bit down.
+    fn write_code(&mut self, code: u32, len: u32) {
+        self.write(code.reverse_bits() >> (32 - len), len);
+    }
+
+    fn finish(mut self) -> Vec<u8> {
+        if self.len > 0 {
+            self.bytes.push(self.bits as u8);
+        }
+        self.bytes
+    }
+}
+
+const LENGTH_BASES: [u16; 29] = [
+    3, 4, 5, 6, 7, 8, 9, 10, 11, 13, 15, 17, 19, 23, 27, 31, 35, 43, 51, 59, 67, 83, 99, 115, 131,
+    163, 195, 227, 258,
+];
+const LENGTH_EXTRA: [u8; 29] = [
+    0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 2, 2, 2, 2, 3, 3, 3, 3, 4, 4, 4, 4, 5, 5, 5, 5, 0,
+];
+const DISTANCE_BASES: [u16; 30] = [
+    1, 2, 3, 4, 5, 7, 9, 13, 17, 25, 33, 49, 65, 97, 129, 193, 257, 385, 513, 769, 1025, 1537,
+    2049, 3073, 4097, 6145, 8193, 12289, 16385, 24577,
+];
+const DISTANCE_EXTRA: [u8; 30] = [
+    0, 0, 0, 0, 1, 1, 2, 2, 3, 3, 4, 4, 5, 5, 6, 6, 7, 7, 8, 8, 9, 9, 10, 10, 11, 11, 12, 12, 13,
+    13,
+];
+
+const WINDOW: usize = 32 * 1024;
+const MIN_MATCH: usize = 3;
+const MAX_MATCH: usize = 258;
+/// How many earlier positions with the same hash are tried for a match.
+const MAX_CHAIN: usize = 64;
+
+/// A single fixed Huffman block with greedy LZ77 matching. Frames are mostly runs and
+/// repeated lines, which this compresses well without dynamic Huffman tables.
+fn deflate(data: &[u8]) -> Vec<u8> {
+    let mut out = BitWriter::default();
+    // Final block, fixed Huffman codes.
+    out.write(1, 1);
+    out.write(1, 2);
+
+    let hash = |i: usize| {
+        let key = (data[i] as usize) << 16 | (data[i + 1] as usize) << 8 | data[i + 2] as usize;
+        (key.wrapping_mul(2_654_435_761) >> 16) & 0xffff
+    };
+    let mut head = vec![usize::MAX; 1 << 16];
+    let mut prev = vec![usize::MAX; WINDOW];
+    let insert = |i: usize, head: &mut [usize], prev: &mut [usize]| {
+        if i + MIN_MATCH <= data.len() {
+            let h = hash(i);
+            prev[i % WINDOW] = head[h];
+            head[h] = i;
+        }
+    };
+
+    let mut i = 0;
+    while i < data.len() {
+        let (mut best_len, mut best_dist) = (0, 0);
+
+        if i + MIN_MATCH <= data.len() {
+            let max_len = MAX_MATCH.min(data.len() - i);
+            let mut candidate = head[hash(i)];
+
+            for _ in 0..MAX_CHAIN {
+                if candidate == usize::MAX || i - candidate > WINDOW - 1 {
+                    break;
+                }
+
+                let len = (0..max_len)
+                    .take_while(|&k| data[candidate + k] == data[i + k])
+                    .count();
+                if len > best_len {
+                    (best_len, best_dist) = (len, i - candidate);
+                    if len == max_len {
+                        break;
+                    }
+                }
+
+                let next = prev[candidate % WINDOW];
+                if next == usize::MAX || next >= candidate {
+                    break;
+                }
+                candidate = next;
+            }
+        }
+
+        if best_len >= MIN_MATCH {
+            write_match(&mut out, best_len, best_dist);
+            for k in i..i + best_len {
+                insert(k, &mut head, &mut prev);
+            }
+            i += best_len;
+        } else {
+            write_literal(&mut out, data[i] as u32);
+            insert(i, &mut head, &mut prev);
+            i += 1;
+        }
+    }
+
+    write_literal(&mut out, 256);
+    out.finish()
+}
+
+/// Fixed Huffman code of a literal, a length symbol or the end of block.
+fn write_literal(out: &mut BitWriter, symbol: u32) {
+    match symbol {
+        0..=143 => out.write_code(0x30 + symbol, 8),
+        144..=255 => out.write_code(0x190 + symbol - 144, 9),
+        256..=279 => out.write_code(symbol - 256, 7),
+        _ => out.write_code(0xc0 + symbol - 280, 8),
+    }
+}
+
+fn write_match(out: &mut BitWriter, len: usize, dist: usize) {
+    let code = LENGTH_BASES.partition_point(|&base| base as usize <= len) - 1;
+    write_literal(out, 257 + code as u32);
+    out.write(
+        (len - LENGTH_BASES[code] as usize) as u32,
+        LENGTH_EXTRA[code] as u32,
+    );
+
+    let code = DISTANCE_BASES.partition_point(|&base| base as usize <= dist) - 1;
+    out.write_code(code as u32, 5);
+    out.write(
+        (dist - DISTANCE_BASES[code] as usize) as u32,
+        DISTANCE_EXTRA[code] as u32,
+    );
+}
+
+/// An endlessly looping GIF of equally sized images.
+fn gif(images: &[Image], palette: &Palette, delay: Duration) -> io::Result<Vec<u8>> {
+    // The colour table has a power of two entries, at least 16 for all tiles.
+    const BITS: u8 = 4;
+
+    let (width, height) = images
+        .first()
+        .map_or((0, 0), |image| (image.width, image.height));
+    let (width, height) = (side::<u16>(width, "GIF")?, side::<u16>(height, "GIF")?);
+    let mut data = b"GIF89a".to_vec();
+
+    data.extend_from_slice(&width.to_le_bytes());
+    data.extend_from_slice(&height.to_le_bytes());
+    // Global colour table of 2^BITS entries.
+    data.extend_from_slice(&[0x80 | (BITS - 1) << 4 | (BITS - 1), 0, 0]);
+    for i in 0..1 << BITS {
+        data.extend_from_slice(palette.colours.get(i).unwrap_or(&[0; 3]));
+    }
+
+    // NETSCAPE2.0 extension, loop forever.
+    data.extend_from_slice(b"\x21\xff\x0bNETSCAPE2.0\x03\x01\x00\x00\x00");
+
+    let centis = (delay.as_millis() / 10).min(u16::MAX as u128) as u16;
+    for image in images {
+        // Graphic control extension with the delay.
+        data.extend_from_slice(&[0x21, 0xf9, 4, 0]);
+        data.extend_from_slice(&centis.to_le_bytes());
+        data.extend_from_slice(&[0, 0]);
+
+        data.push(0x2c);
+        data.extend_from_slice(&[0, 0, 0, 0]);
+        data.extend_from_slice(&side::<u16>(image.width, "GIF")?.to_le_bytes());
+        data.extend_from_slice(&side::<u16>(image.height, "GIF")?.to_le_bytes());
+        data.push(0);
+
+        data.push(BITS);
+        for block in lzw(&image.pixels, BITS).chunks(255) {
+            data.push(block.len() as u8);
+            data.extend_from_slice(block);
+        }
+        data.push(0);
+    }
+
+    data.push(0x3b);
+    Ok(data)
+}
+
+/// GIF flavoured LZW with variable code sizes up to 12 bits.
+fn lzw(pixels: &[u8], min_bits: u8) -> Vec<u8> {
+    const MAX_CODES: u16 = 1 << 12;
+
+    let clear = 1u16 << min_bits;
+    let end = clear + 1;
+    let mut out = BitWriter::default();
+    // Code of every string, keyed by the code of its prefix and its last pixel.
+    let mut table: Vec<[u16; 16]> = Vec::new();
+    let mut next = end + 1;
+    let mut bits = min_bits as u32 + 1;
+
+    let reset = |table: &mut Vec<[u16; 16]>| {
+        table.clear();
+        table.resize(MAX_CODES as usize, [0; 16]);
+    };
+    reset(&mut table);
+    out.write(clear as u32, bits);
+
+    let Some((&first, rest)) = pixels.split_first() else {
+        out.write(end as u32, bits);
+        return out.finish();
+    };
+    let mut current = first as u16;
+
+    for &pixel in rest {
+        let entry = table[current as usize][pixel as usize];
+        if entry != 0 {
+            current = entry;
+            continue;
+        }
+
+        out.write(current as u32, bits);
+
+        if next < MAX_CODES {
+            table[current as usize][pixel as usize] = next;
+            if next == 1 << bits && bits < 12 {
+                bits += 1;
+            }
+            next += 1;
+        } else {
+            out.write(clear as u32, bits);
+            reset(&mut table);
+            next = end + 1;
+            bits = min_bits as u32 + 1;
+        }
+
+        current = pixel as u16;
+    }
+
+    out.write(current as u32, bits);
+    out.write(end as u32, bits);
+    out.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        ExportOptions, Image, ImageFormat, Palette, adler32, crc32, deflate, export, gif, lzw,
+    };
+    use crate::{
+        grid::Grid,
+        parse::{ParseError, ParseErrorKind},
+        visualize::{Frame, Tile},
+    };
+    use std::{env, fs, io, process, time::Duration};
+
+    fn frame() -> Frame {
+        Frame {
+            caption: String::new(),
+            tiles: Grid::new(2, 1, vec![Tile::Paper, Tile::Beam]),
+        }
+    }
+
+    #[test]
+    fn test_checksums() {
+        assert_eq!(0xcbf4_3926, crc32(b"123456789"));
+        assert_eq!(0x11e6_0398, adler32(b"Wikipedia"));
+    }
+
+    #[test]
+    fn test_deflate() {
+        assert_eq!(vec![0x4b, 0x4c, 0x4a, 0x06, 0x00], deflate(b"abc"));
+        // A literal and a match of 9 at distance 1.
+        assert_eq!(vec![0x4b, 0x84, 0x03, 0x00], deflate(b"aaaaaaaaaa"));
+    }
+
+    /// Decodes GIF LZW, to check the encoder against.
+    fn unlzw(data: &[u8], min_bits: u8) -> Vec<u8> {
+        let clear = 1usize << min_bits;
+        let mut bits = min_bits as usize + 1;
+        let (mut acc, mut len, mut pos) = (0usize, 0, 0);
+        let mut table: Vec<Vec<u8>> = Vec::new();
+        let mut previous: Option<Vec<u8>> = None;
+        let mut out = Vec::new();
+
+        loop {
+            while len < bits {
+                acc |= (data[pos] as usize) << len;
+                len += 8;
+                pos += 1;
+            }
+            let code = acc & ((1 << bits) - 1);
+            acc >>= bits;
+            len -= bits;
+
+            if code == clear {
+                table = (0..clear as u8).map(|i| vec![i]).collect();
+                table.extend([vec![], vec![]]);
+                bits = min_bits as usize + 1;
+                previous = None;
+                continue;
+            }
+            if code == clear + 1 {
+                return out;
+            }
+
+            let entry = match (table.get(code), &previous) {
+                (Some(entry), _) => entry.clone(),
+                (None, Some(prev)) => [prev.clone(), vec![prev[0]]].concat(),
+                (None, None) => panic!("invalid code {code}"),
+            };
+            if let Some(prev) = previous {
+                table.push([prev, vec![entry[0]]].concat());
+            }
+            if table.len() == 1 << bits && bits < 12 {
+                bits += 1;
+            }
+
+            out.extend_from_slice(&entry);
+            previous = Some(entry);
+        }
+    }
+
+    #[test]
+    fn test_lzw() {
+        // Random enough to fill the code table and start over.
+        let mut state = 1u32;
+        let pixels: Vec<u8> = (0..20_000)
+            .map(|_| {
+                state = state.wrapping_mul(1_103_515_245).wrapping_add(12345);
+                (state >> 16) as u8 % 10
+            })
+            .collect();
+
+        assert_eq!(pixels, unlzw(&lzw(&pixels, 4), 4));
+        assert_eq!(Vec::<u8>::new(), unlzw(&lzw(&[], 4), 4));
+    }
+
+    #[test]
+    fn test_render() {
+        let image = Image::render(&frame(), 2);
+
+        assert_eq!((4, 2), (image.width, image.height));
+        assert_eq!(vec![1, 1, 8, 8, 1, 1, 8, 8], image.pixels);
+        assert_eq!(
+            [b"P6\n4 2\n255\n".as_slice(), &[230; 3], &[230; 3]].concat(),
+            image.ppm(&Palette::default())[..17]
+        );
+    }
+
+    #[test]
+    fn test_parse_palette() {
+        let palette: Palette = "beam=#ffcc00, empty=#000000".parse().unwrap();
+
+        assert_eq!([255, 204, 0], palette.colours[8]);
+        assert_eq!([0, 0, 0], palette.colours[0]);
+        assert_eq!(Palette::default().colours[1], palette.colours[1]);
+        assert_eq!(
+            Err(ParseError::new(
+                1,
+                21,
+                ParseErrorKind::UnexpectedChar {
+                    expected: "hex digit",
+                    found: 'g'
+                }
+            )),
+            "paper=#ffffff,beam=#g00000".parse::<Palette>().map(|_| ())
+        );
+    }
+
+    #[test]
+    fn test_gif_size() {
+        let palette = ExportOptions::default().palette;
+        let image = |width: usize| Image {
+            width,
+            height: 1,
+            pixels: vec![0; width],
+        };
+
+        assert!(gif(&[image(65535)], &palette, Duration::ZERO).is_ok());
+        assert_eq!(
+            io::ErrorKind::InvalidInput,
+            gif(&[image(65536)], &palette, Duration::ZERO)
+                .unwrap_err()
+                .kind()
+        );
+    }
+
+    #[test]
+    fn test_export() {
+        let dir = env::temp_dir().join(format!("aoc-export-{}", process::id()));
+        let options = ExportOptions {
+            format: ImageFormat::Png,
+            gif: Some(Duration::from_millis(50)),
+            ..ExportOptions::default()
+        };
+
+        let paths = export(&[frame(), frame()], &options, &dir).unwrap();
+
+        assert_eq!(
+            vec![
+                dir.join("frame-0001.png"),
+                dir.join("frame-0002.png"),
+                dir.join("animation.gif")
+            ],
+            paths
+        );
+        assert!(
+            fs::read(&paths[0])
+                .unwrap()
+                .starts_with(b"\x89PNG\r\n\x1a\n")
+        );
+        let gif = fs::read(&paths[2]).unwrap();
+        assert!(gif.starts_with(b"GIF89a\x08\x00\x04\x00"));
+        assert!(gif.ends_with(b"\x3b"));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
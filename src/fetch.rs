@@ -0,0 +1,171 @@
+use crate::{
+    config::YEAR,
+    http::{Client, HttpError},
+};
+use std::{
+    error::Error,
+    fmt::Display,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Fetched {
+    /// The input was already there and nothing was downloaded.
+    Cached(PathBuf),
+    Downloaded(PathBuf),
+}
+
+/// `NN.txt` in `input_dir` if it has content. `aoc new` leaves an empty one behind, which
+/// doesn't count.
+pub fn cached(input_dir: &Path, day: u8) -> Option<PathBuf> {
+    let path = input_path(input_dir, day);
+    let len = fs::metadata(&path).ok()?.len();
+
+    (len > 0).then_some(path)
+}
+
+fn input_path(input_dir: &Path, day: u8) -> PathBuf {
+    input_dir.join(format!("{day:02}.txt"))
+}
+
+/// Downloads the input of `day` into `input_dir`, unless it is [`cached`].
+pub fn fetch(client: &Client, input_dir: &Path, day: u8) -> Result<Fetched, FetchError> {
+    if let Some(path) = cached(input_dir, day) {
+        return Ok(Fetched::Cached(path));
+    }
+
+    let response = client.get(&format!("/{YEAR}/day/{day}/input"))?;
+
+    match response.status {
+        200 => {}
+        404 => return Err(FetchError::NotAvailable { day }),
+        400 | 401 | 403 => return Err(FetchError::Unauthorized),
+        status => {
+            return Err(FetchError::Status {
+                status,
+                message: response.body.lines().next().unwrap_or_default().to_string(),
+            });
+        }
+    }
+
+    let path = input_path(input_dir, day);
+    let partial = path.with_extension("txt.part");
+    let write = || {
+        fs::create_dir_all(input_dir)?;
+        // A failed download never leaves a truncated input that counts as cached.
+        fs::write(&partial, &response.body)?;
+        fs::rename(&partial, &path)
+    };
+    write().map_err(|err| FetchError::Io {
+        path: path.clone(),
+        err,
+    })?;
+
+    Ok(Fetched::Downloaded(path))
+}
+
+#[derive(Debug)]
+pub enum FetchError {
+    Http(HttpError),
+    /// The puzzle is not unlocked yet.
+    NotAvailable {
+        day: u8,
+    },
+    /// The session token is missing or expired.
+    Unauthorized,
+    Status {
+        status: u16,
+        message: String,
+    },
+    Io {
+        path: PathBuf,
+        err: io::Error,
+    },
+}
+
+impl Display for FetchError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FetchError::Http(err) => err.fmt(f),
+            FetchError::NotAvailable { day } => {
+                write!(f, "the input of day {day:02} is not available yet")
+            }
+            FetchError::Unauthorized => {
+                write!(
+                    f,
+                    "the site rejected the session token, it may have expired"
+                )
+            }
+            FetchError::Status { status, message } => {
+                write!(f, "unexpected response {status}: {message}")
+            }
+            FetchError::Io { path, err } => write!(f, "failed to write {}: {err}", path.display()),
+        }
+    }
+}
+
+impl Error for FetchError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            FetchError::Http(err) => Some(err),
+            FetchError::Io { err, .. } => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<HttpError> for FetchError {
+    fn from(err: HttpError) -> Self {
+        FetchError::Http(err)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{FetchError, Fetched, cached, fetch};
+    use crate::{http::Client, test_support::MockServer};
+    use std::{env, fs, process};
+
+    #[test]
+    fn test_fetch() {
+        let dir = env::temp_dir().join(format!("aoc-fetch-{}", process::id()));
+        let server = MockServer::start(vec![
+            (200, "1\n2\n".to_string()),
+            (404, "Not found".to_string()),
+            (400, "Puzzle inputs differ by user.".to_string()),
+        ]);
+        let client = Client::new(server.url(), "secret");
+
+        assert_eq!(None, cached(&dir, 3));
+        assert_eq!(
+            Fetched::Downloaded(dir.join("03.txt")),
+            fetch(&client, &dir, 3).unwrap()
+        );
+        assert_eq!("1\n2\n", fs::read_to_string(dir.join("03.txt")).unwrap());
+        assert_eq!(
+            Fetched::Cached(dir.join("03.txt")),
+            fetch(&client, &dir, 3).unwrap()
+        );
+
+        assert!(matches!(
+            fetch(&client, &dir, 12),
+            Err(FetchError::NotAvailable { day: 12 })
+        ));
+        assert!(!dir.join("12.txt").exists());
+
+        // An empty placeholder from `aoc new` is not cached.
+        fs::write(dir.join("04.txt"), "").unwrap();
+        assert!(matches!(
+            fetch(&client, &dir, 4),
+            Err(FetchError::Unauthorized)
+        ));
+
+        let requests = server.requests();
+        assert_eq!(3, requests.len());
+        assert!(requests[0].starts_with("GET /2025/day/3/input HTTP/1.1\r\n"));
+        assert!(requests[2].starts_with("GET /2025/day/4/input HTTP/1.1\r\n"));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
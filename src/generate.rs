@@ -0,0 +1,380 @@
+use crate::{
+    Solution,
+    days::{
+        day01::Day01, day02::Day02, day03::Day03, day04::Day04, day05::Day05, day06::Day06,
+        day07::Day07,
+    },
+};
+use std::{
+    fmt::Display,
+    io::{self, Write},
+    ops::RangeInclusive,
+};
+
+/// Size used when none is given: small enough to read, large enough to be interesting.
+pub const DEFAULT_SIZE: usize = 10;
+
+/// A SplitMix64 generator: tiny, fast and the same on every platform, so a seed always
+/// reproduces the same input.
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Rng {
+        Rng { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform in `0..n`. `n` must not be 0.
+    pub fn below(&mut self, n: u64) -> u64 {
+        ((self.next_u64() as u128 * n as u128) >> 64) as u64
+    }
+
+    pub fn range(&mut self, range: RangeInclusive<u64>) -> u64 {
+        let (start, end) = range.into_inner();
+
+        match (end - start).checked_add(1) {
+            Some(len) => start + self.below(len),
+            None => self.next_u64(),
+        }
+    }
+
+    /// `true` with probability `p`.
+    pub fn chance(&mut self, p: f64) -> bool {
+        ((self.next_u64() >> 11) as f64 / (1u64 << 53) as f64) < p
+    }
+
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len() as u64) as usize]
+    }
+
+    /// A number with `digits` digits, `1..=9` for a single one.
+    fn digits(&mut self, digits: u32) -> u64 {
+        self.range(10u64.pow(digits - 1)..=10u64.pow(digits) - 1)
+    }
+}
+
+/// Limits of the numbers in an input.
+struct Shape {
+    bank_len: RangeInclusive<u64>,
+    max_id_digits: u64,
+    max_id_span: u64,
+    max_ingredient_id: u64,
+}
+
+/// About what the real inputs look like.
+const REAL: Shape = Shape {
+    bank_len: 100..=100,
+    max_id_digits: 10,
+    // Every ID in a range is checked, so they can't be arbitrarily wide.
+    max_id_span: 100_000,
+    max_ingredient_id: 500_000_000_000_000,
+};
+
+/// Small enough for the brute force solutions in [`crate::reference`].
+const SMALL: Shape = Shape {
+    bank_len: 12..=16,
+    max_id_digits: 6,
+    max_id_span: 1_000,
+    max_ingredient_id: 1_000,
+};
+
+/// A random input for `day`, shaped like the real ones, `None` if there is no generator for it.
+///
+/// What `size` counts depends on the day: rotations, ranges, banks, the side of the grid,
+/// fresh ranges, problems or the side of the manifold.
+pub fn generate(day: u8, seed: u64, size: usize) -> Option<String> {
+    to_string(|out| generate_shaped(day, seed, size, &REAL, out))
+}
+
+/// Like [`generate`], but with short banks and small IDs, so that the brute force solutions
+/// can keep up.
+pub fn generate_small(day: u8, seed: u64, size: usize) -> Option<String> {
+    to_string(|out| generate_shaped(day, seed, size, &SMALL, out))
+}
+
+/// Like [`generate`], but writes the input to `out` as it goes, so that it can be larger than
+/// memory. Only the days after 03 need memory that grows with `size`.
+pub fn write(day: u8, seed: u64, size: usize, out: &mut dyn Write) -> Option<io::Result<()>> {
+    generate_shaped(day, seed, size, &REAL, out)
+}
+
+fn to_string(generate: impl FnOnce(&mut dyn Write) -> Option<io::Result<()>>) -> Option<String> {
+    let mut out = Vec::new();
+    // Writing to a `Vec` can't fail.
+    generate(&mut out)?.ok()?;
+
+    String::from_utf8(out).ok()
+}
+
+fn generate_shaped(
+    day: u8,
+    seed: u64,
+    size: usize,
+    shape: &Shape,
+    out: &mut dyn Write,
+) -> Option<io::Result<()>> {
+    let rng = &mut Rng::new(seed);
+    let lines = &mut Joined::new(out, "\n");
+
+    let written = match day {
+        Day01::DAY => rotations(rng, size, lines),
+        Day02::DAY => id_ranges(rng, size, shape, &mut Joined::new(lines.out, ",")),
+        Day03::DAY => banks(rng, size, shape, lines),
+        Day04::DAY => paper_grid(rng, size, lines),
+        Day05::DAY => database(rng, size, shape, lines),
+        Day06::DAY => worksheet(rng, size, lines),
+        Day07::DAY => manifold(rng, size, lines),
+        _ => return None,
+    };
+
+    Some(written)
+}
+
+/// Writes items with a separator between them, like [`slice::join`].
+struct Joined<'a> {
+    out: &'a mut dyn Write,
+    separator: &'static str,
+    first: bool,
+}
+
+impl<'a> Joined<'a> {
+    fn new(out: &'a mut dyn Write, separator: &'static str) -> Joined<'a> {
+        Joined {
+            out,
+            separator,
+            first: true,
+        }
+    }
+
+    fn item(&mut self, item: impl Display) -> io::Result<()> {
+        if !self.first {
+            self.out.write_all(self.separator.as_bytes())?;
+        }
+        self.first = false;
+
+        write!(self.out, "{item}")
+    }
+}
+
+/// `L68`, `R48` and so on, one per line.
+fn rotations(rng: &mut Rng, size: usize, out: &mut Joined) -> io::Result<()> {
+    for _ in 0..size {
+        out.item(format_args!(
+            "{}{}",
+            rng.choose(&['L', 'R']),
+            rng.range(1..=999)
+        ))?;
+    }
+
+    Ok(())
+}
+
+/// `11-22,95-115,...`
+fn id_ranges(rng: &mut Rng, size: usize, shape: &Shape, out: &mut Joined) -> io::Result<()> {
+    for _ in 0..size {
+        let digits = rng.range(1..=shape.max_id_digits) as u32;
+        let start = rng.digits(digits);
+        let end = start + rng.range(0..=start.min(shape.max_id_span));
+        out.item(format_args!("{start}-{end}"))?;
+    }
+
+    Ok(())
+}
+
+/// Lines of digits from 0 to 9.
+fn banks(rng: &mut Rng, size: usize, shape: &Shape, out: &mut Joined) -> io::Result<()> {
+    for _ in 0..size {
+        let bank: String = (0..rng.range(shape.bank_len.clone()))
+            .map(|_| char::from(b'0' + rng.range(0..=9) as u8))
+            .collect();
+        out.item(bank)?;
+    }
+
+    Ok(())
+}
+
+/// A `size` by `size` grid, mostly paper like the real one.
+fn paper_grid(rng: &mut Rng, size: usize, out: &mut Joined) -> io::Result<()> {
+    for _ in 0..size {
+        let row: String = (0..size)
+            .map(|_| if rng.chance(0.6) { '@' } else { '.' })
+            .collect();
+        out.item(row)?;
+    }
+
+    Ok(())
+}
+
+/// `size` fresh ranges, some of them overlapping, then as many available IDs, about half of
+/// them fresh.
+fn database(rng: &mut Rng, size: usize, shape: &Shape, out: &mut Joined) -> io::Result<()> {
+    let max_id = shape.max_ingredient_id;
+    let ranges: Vec<(u64, u64)> = (0..size)
+        .map(|_| {
+            let start = rng.range(1..=max_id);
+            (start, start + rng.range(0..=max_id / 100))
+        })
+        .collect();
+
+    for (start, end) in &ranges {
+        out.item(format_args!("{start}-{end}"))?;
+    }
+    out.item("")?;
+
+    for _ in 0..size {
+        let id = match ranges.is_empty() || rng.chance(0.5) {
+            true => rng.range(1..=max_id),
+            false => {
+                let &(start, end) = rng.choose(&ranges);
+                rng.range(start..=end)
+            }
+        };
+        out.item(id)?;
+    }
+
+    Ok(())
+}
+
+const WORKSHEET_ROWS: usize = 4;
+
+/// `size` problems of four numbers with up to four digits. The numbers of a problem are all
+/// aligned left or all aligned right, and every line is padded to the same length.
+///
+/// Like in the real one, the digits in a column have no gaps between them, which takes the
+/// numbers of a problem ordered by length.
+fn worksheet(rng: &mut Rng, size: usize, out: &mut Joined) -> io::Result<()> {
+    // A worksheet without problems has no line of signs to parse.
+    let size = size.max(1);
+    let mut lines = vec![String::new(); WORKSHEET_ROWS + 1];
+
+    for i in 0..size {
+        let mut lengths: Vec<u32> = (0..WORKSHEET_ROWS)
+            .map(|_| rng.range(1..=4) as u32)
+            .collect();
+        lengths.sort_unstable();
+        if rng.chance(0.5) {
+            lengths.reverse();
+        }
+
+        let numbers: Vec<String> = lengths
+            .into_iter()
+            .map(|digits| rng.digits(digits).to_string())
+            .collect();
+        let width = numbers.iter().map(String::len).max().unwrap_or(0);
+        let left = rng.chance(0.5);
+        let sign = rng.choose(&['+', '*']);
+
+        for (line, number) in lines.iter_mut().zip(&numbers) {
+            if i > 0 {
+                line.push(' ');
+            }
+            match left {
+                true => line.push_str(&format!("{number:<width$}")),
+                false => line.push_str(&format!("{number:>width$}")),
+            }
+        }
+
+        let signs = &mut lines[WORKSHEET_ROWS];
+        if i > 0 {
+            signs.push(' ');
+        }
+        signs.push_str(&format!("{sign:<width$}"));
+    }
+
+    lines.into_iter().try_for_each(|line| out.item(line))
+}
+
+/// A `size` by `size` manifold with the start in the middle of the first row. Like in the
+/// real one, splitters only sit on every other row within reach of the start, never next to
+/// each other.
+fn manifold(rng: &mut Rng, size: usize, out: &mut Joined) -> io::Result<()> {
+    // The smallest manifold is the row with the start.
+    let size = size.max(1);
+    let start = size / 2;
+
+    for row in 0..size {
+        let line: String = (0..size)
+            .map(|col| {
+                let level = row / 2;
+                let reachable = row % 2 == 0 && col.abs_diff(start) < level;
+
+                if row == 0 && col == start {
+                    'S'
+                } else if reachable && (col + start + level) % 2 == 1 && rng.chance(0.5) {
+                    '^'
+                } else {
+                    '.'
+                }
+            })
+            .collect();
+        out.item(line)?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DEFAULT_SIZE, Rng, generate};
+    use crate::{Part, days};
+
+    #[test]
+    fn test_rng() {
+        let mut rng = Rng::new(1234567);
+
+        assert_eq!(6457827717110365317, rng.next_u64());
+        assert_eq!(3203168211198807973, rng.next_u64());
+
+        for _ in 0..1000 {
+            assert!((3..=7).contains(&rng.range(3..=7)));
+        }
+        rng.range(0..=u64::MAX);
+    }
+
+    #[test]
+    fn test_generate() {
+        assert_eq!(None, generate(25, 0, DEFAULT_SIZE));
+
+        for solution in days::ALL {
+            for seed in 0..5 {
+                let input = generate(solution.day(), seed, DEFAULT_SIZE).unwrap();
+                let parsed = solution
+                    .parse(&input)
+                    .unwrap_or_else(|err| panic!("seed {seed}: {err}\n{input}"));
+
+                for part in Part::BOTH {
+                    if let Err(err) = solution.solve(parsed.as_ref(), part) {
+                        panic!("seed {seed} part {part}: {err}\n{input}");
+                    }
+                }
+            }
+
+            assert_eq!(
+                generate(solution.day(), 1, DEFAULT_SIZE),
+                generate(solution.day(), 1, DEFAULT_SIZE)
+            );
+            assert_ne!(
+                generate(solution.day(), 1, DEFAULT_SIZE),
+                generate(solution.day(), 2, DEFAULT_SIZE)
+            );
+
+            for size in 0..4 {
+                let input = generate(solution.day(), 1, size).unwrap();
+                if let Err(err) = solution.parse(&input) {
+                    panic!("size {size}: {err}\n{input}");
+                }
+            }
+        }
+    }
+}
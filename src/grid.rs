@@ -0,0 +1,379 @@
+use crate::parse::{ParseError, ParseErrorKind};
+use std::{
+    fmt::{Display, Write},
+    ops::{Index, IndexMut},
+    str::FromStr,
+};
+
+/// A grid cell that is written as a single character in puzzle inputs.
+pub trait Cell: Sized {
+    /// The valid characters for error messages, e.g. `"'.' or '@'"`.
+    const EXPECTED: &'static str;
+
+    fn from_char(ch: char) -> Option<Self>;
+
+    fn to_char(&self) -> char;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Position {
+    pub row: usize,
+    pub col: usize,
+}
+
+impl Position {
+    pub const fn new(row: usize, col: usize) -> Position {
+        Position { row, col }
+    }
+
+    /// Moves by the given row and column delta. `None` if the result would be negative.
+    pub fn offset(self, d_row: isize, d_col: isize) -> Option<Position> {
+        Some(Position {
+            row: self.row.checked_add_signed(d_row)?,
+            col: self.col.checked_add_signed(d_col)?,
+        })
+    }
+}
+
+impl Display for Position {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({},{})", self.row, self.col)
+    }
+}
+
+/// Up, right, down, left.
+pub const DIRECTIONS_4: [(isize, isize); 4] = [(-1, 0), (0, 1), (1, 0), (0, -1)];
+
+/// Clockwise, starting from the top-left corner.
+pub const DIRECTIONS_8: [(isize, isize); 8] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+    (1, 0),
+    (1, -1),
+    (0, -1),
+];
+
+/// Rectangular grid stored row by row in one `Vec`.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    /// Panics if `cells.len() != width * height`.
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Grid<T> {
+        assert_eq!(
+            width * height,
+            cells.len(),
+            "Grid of {width}x{height} needs {} cells",
+            width * height
+        );
+
+        Grid {
+            cells,
+            width,
+            height,
+        }
+    }
+
+    pub fn filled(width: usize, height: usize, value: T) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid::new(width, height, vec![value; width * height])
+    }
+
+    pub const fn width(&self) -> usize {
+        self.width
+    }
+
+    pub const fn height(&self) -> usize {
+        self.height
+    }
+
+    pub const fn contains(&self, pos: Position) -> bool {
+        pos.row < self.height && pos.col < self.width
+    }
+
+    fn index_of(&self, pos: Position) -> Option<usize> {
+        self.contains(pos).then_some(pos.row * self.width + pos.col)
+    }
+
+    pub fn get(&self, pos: Position) -> Option<&T> {
+        self.index_of(pos).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, pos: Position) -> Option<&mut T> {
+        self.index_of(pos).map(|i| &mut self.cells[i])
+    }
+
+    /// Every position, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Position> + use<T> {
+        let width = self.width;
+        (0..self.cells.len()).map(move |i| Position::new(i / width, i % width))
+    }
+
+    /// Every cell with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn row(&self, row: usize) -> Option<&[T]> {
+        (row < self.height).then(|| &self.cells[row * self.width..(row + 1) * self.width])
+    }
+
+    pub fn row_mut(&mut self, row: usize) -> Option<&mut [T]> {
+        (row < self.height).then(|| &mut self.cells[row * self.width..(row + 1) * self.width])
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // `max(1)` keeps `chunks` happy for grids with empty rows, which have no cells anyway.
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// Cells of one column, top to bottom. Empty if the column is out of bounds.
+    pub fn column(&self, col: usize) -> impl Iterator<Item = &T> {
+        let rows = if col < self.width { self.height } else { 0 };
+        (0..rows).map(move |row| &self.cells[row * self.width + col])
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|col| self.column(col))
+    }
+
+    /// In-bounds orthogonal neighbours of `pos`.
+    pub fn neighbours4(&self, pos: Position) -> impl Iterator<Item = Position> + use<T> {
+        self.neighbours(pos, &DIRECTIONS_4)
+    }
+
+    /// In-bounds orthogonal and diagonal neighbours of `pos`.
+    pub fn neighbours8(&self, pos: Position) -> impl Iterator<Item = Position> + use<T> {
+        self.neighbours(pos, &DIRECTIONS_8)
+    }
+
+    fn neighbours(
+        &self,
+        pos: Position,
+        directions: &'static [(isize, isize)],
+    ) -> impl Iterator<Item = Position> + use<T> {
+        let (width, height) = (self.width, self.height);
+
+        directions
+            .iter()
+            .filter_map(move |&(d_row, d_col)| pos.offset(d_row, d_col))
+            .filter(move |p| p.row < height && p.col < width)
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid::new(self.width, self.height, self.cells.iter().map(f).collect())
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Position) -> &Self::Output {
+        self.get(pos)
+            .unwrap_or_else(|| panic!("Position {pos} is outside of the grid"))
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, pos: Position) -> &mut Self::Output {
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("Position {pos} is outside of the grid"))
+    }
+}
+
+impl<T: Cell> FromStr for Grid<T> {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut cells = Vec::new();
+        let mut width = None;
+        let mut height = 0;
+
+        for (row, line) in s.lines().enumerate() {
+            let len_before = cells.len();
+
+            for (col, ch) in line.chars().enumerate() {
+                let cell = T::from_char(ch).ok_or(ParseError::new(
+                    row + 1,
+                    col + 1,
+                    ParseErrorKind::UnexpectedChar {
+                        expected: T::EXPECTED,
+                        found: ch,
+                    },
+                ))?;
+
+                cells.push(cell);
+            }
+
+            let row_width = cells.len() - len_before;
+            match width {
+                None => width = Some(row_width),
+                Some(width) if width != row_width => {
+                    return Err(ParseError::new(
+                        row + 1,
+                        row_width.min(width) + 1,
+                        ParseErrorKind::Count {
+                            items: "cells",
+                            expected: width,
+                            found: row_width,
+                        },
+                    ));
+                }
+                Some(_) => {}
+            }
+
+            height += 1;
+        }
+
+        Ok(Grid::new(width.unwrap_or(0), height, cells))
+    }
+}
+
+impl<T: Cell> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (i, row) in self.rows().enumerate() {
+            if i > 0 {
+                f.write_char('\n')?;
+            }
+
+            for cell in row {
+                f.write_char(cell.to_char())?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Cell, Grid, Position};
+    use crate::parse::{ParseError, ParseErrorKind};
+
+    impl Cell for char {
+        const EXPECTED: &'static str = "letter or digit";
+
+        fn from_char(ch: char) -> Option<Self> {
+            ch.is_ascii_alphanumeric().then_some(ch)
+        }
+
+        fn to_char(&self) -> char {
+            *self
+        }
+    }
+
+    const TEST: &str = r#"abc
+def
+ghi"#;
+
+    fn p(row: usize, col: usize) -> Position {
+        Position::new(row, col)
+    }
+
+    fn test_grid() -> Grid<char> {
+        TEST.parse().unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = test_grid();
+
+        assert_eq!(3, grid.width());
+        assert_eq!(3, grid.height());
+        assert_eq!(TEST, grid.to_string());
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            Err(ParseError::new(
+                2,
+                2,
+                ParseErrorKind::Count {
+                    items: "cells",
+                    expected: 2,
+                    found: 1
+                }
+            )),
+            "ab\nc".parse::<Grid<char>>()
+        );
+        assert_eq!(
+            Err(ParseError::new(
+                1,
+                2,
+                ParseErrorKind::UnexpectedChar {
+                    expected: "letter or digit",
+                    found: '.'
+                }
+            )),
+            "a.".parse::<Grid<char>>()
+        );
+        assert_eq!(Grid::new(0, 0, vec![]), "".parse::<Grid<char>>().unwrap());
+    }
+
+    #[test]
+    fn test_get() {
+        let mut grid = test_grid();
+
+        assert_eq!(Some(&'f'), grid.get(p(1, 2)));
+        assert_eq!(None, grid.get(p(1, 3)));
+        assert_eq!(None, grid.get(p(3, 0)));
+
+        *grid.get_mut(p(0, 0)).unwrap() = 'z';
+        assert_eq!('z', grid[p(0, 0)]);
+        assert_eq!(None, grid.get_mut(p(0, 3)));
+    }
+
+    #[test]
+    fn test_rows_and_columns() {
+        let grid = test_grid();
+
+        assert_eq!(Some(&['d', 'e', 'f'][..]), grid.row(1));
+        assert_eq!(None, grid.row(3));
+        assert_eq!(3, grid.rows().count());
+
+        assert_eq!(
+            vec!['b', 'e', 'h'],
+            grid.column(1).copied().collect::<Vec<_>>()
+        );
+        assert_eq!(0, grid.column(3).count());
+        assert_eq!(
+            vec!['c', 'f', 'i'],
+            grid.columns().last().unwrap().copied().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = test_grid();
+
+        assert_eq!(
+            vec![p(0, 1), p(1, 0)],
+            grid.neighbours4(p(0, 0)).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![p(0, 1), p(1, 1), p(1, 0)],
+            grid.neighbours8(p(0, 0)).collect::<Vec<_>>()
+        );
+        assert_eq!(4, grid.neighbours4(p(1, 1)).count());
+        assert_eq!(8, grid.neighbours8(p(1, 1)).count());
+        assert_eq!(3, grid.neighbours8(p(2, 2)).count());
+    }
+
+    #[test]
+    fn test_iter() {
+        let grid = test_grid();
+
+        assert_eq!(Some((p(2, 1), &'h')), grid.iter().nth(7));
+        assert_eq!(9, grid.positions().count());
+    }
+}
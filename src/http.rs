@@ -0,0 +1,388 @@
+use std::{
+    error::Error,
+    fmt::Display,
+    io::{self, Read, Write},
+    net::TcpStream,
+    process::{Command, Stdio},
+    time::Duration,
+};
+
+/// Sent with every request so the puzzle site can tell who is calling.
+pub const USER_AGENT: &str = concat!(
+    "aoc-2025/",
+    env!("CARGO_PKG_VERSION"),
+    " (Advent of Code runner; caches inputs, never re-downloads them)"
+);
+
+const TIMEOUT: Duration = Duration::from_secs(30);
+
+/// A minimal client for the puzzle site, authenticated with the session cookie.
+///
+/// `http://` URLs are served over a plain [`TcpStream`], which is what the tests use.
+/// `https://` URLs are handed to the `curl` binary, as std has no TLS.
+#[derive(Debug, Clone)]
+pub struct Client {
+    base_url: String,
+    session: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+struct Request<'a> {
+    method: &'static str,
+    url: String,
+    session: &'a str,
+    /// URL-encoded form.
+    body: Option<String>,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: &str) -> Client {
+        Client {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.to_string(),
+        }
+    }
+
+    /// `path` starts with `/` and is appended to the base URL.
+    pub fn get(&self, path: &str) -> Result<Response, HttpError> {
+        self.send(Request {
+            method: "GET",
+            url: format!("{}{path}", self.base_url),
+            session: &self.session,
+            body: None,
+        })
+    }
+
+    pub fn post_form(&self, path: &str, form: &[(&str, &str)]) -> Result<Response, HttpError> {
+        let body = form
+            .iter()
+            .map(|(key, value)| format!("{}={}", url_encode(key), url_encode(value)))
+            .collect::<Vec<_>>()
+            .join("&");
+
+        self.send(Request {
+            method: "POST",
+            url: format!("{}{path}", self.base_url),
+            session: &self.session,
+            body: Some(body),
+        })
+    }
+
+    fn send(&self, request: Request) -> Result<Response, HttpError> {
+        if request.url.starts_with("http://") {
+            send_plain(&request)
+        } else if request.url.starts_with("https://") {
+            send_curl(&request)
+        } else {
+            Err(HttpError::InvalidUrl(request.url))
+        }
+    }
+}
+
+fn send_plain(request: &Request) -> Result<Response, HttpError> {
+    let rest = &request.url["http://".len()..];
+    let (authority, path) = rest.split_at(rest.find('/').unwrap_or(rest.len()));
+    let path = if path.is_empty() { "/" } else { path };
+
+    if authority.is_empty() {
+        return Err(HttpError::InvalidUrl(request.url.clone()));
+    }
+    let address = match authority.contains(':') {
+        true => authority.to_string(),
+        false => format!("{authority}:80"),
+    };
+
+    let mut stream = TcpStream::connect(address)?;
+    stream.set_read_timeout(Some(TIMEOUT))?;
+    stream.set_write_timeout(Some(TIMEOUT))?;
+
+    let mut head = format!(
+        "{} {path} HTTP/1.1\r\nHost: {authority}\r\nUser-Agent: {USER_AGENT}\r\n\
+         Cookie: session={}\r\nConnection: close\r\n",
+        request.method, request.session
+    );
+    if let Some(body) = &request.body {
+        head += "Content-Type: application/x-www-form-urlencoded\r\n";
+        head += &format!("Content-Length: {}\r\n", body.len());
+    }
+    head += "\r\n";
+
+    stream.write_all(head.as_bytes())?;
+    if let Some(body) = &request.body {
+        stream.write_all(body.as_bytes())?;
+    }
+
+    let mut raw = Vec::new();
+    stream.read_to_end(&mut raw)?;
+
+    parse_response(&raw)
+}
+
+/// Parses a whole HTTP/1.1 response of a `Connection: close` request.
+fn parse_response(raw: &[u8]) -> Result<Response, HttpError> {
+    let end = raw
+        .windows(4)
+        .position(|window| window == b"\r\n\r\n")
+        .ok_or(HttpError::Malformed("response without end of headers"))?;
+    let head = String::from_utf8_lossy(&raw[..end]);
+    let body = &raw[end + 4..];
+
+    let mut lines = head.split("\r\n");
+    let status = lines
+        .next()
+        .and_then(|line| line.split_whitespace().nth(1))
+        .and_then(|status| status.parse().ok())
+        .ok_or(HttpError::Malformed("invalid status line"))?;
+
+    let mut chunked = false;
+    let mut length = None;
+    for line in lines {
+        let Some((name, value)) = line.split_once(':') else {
+            continue;
+        };
+        let value = value.trim();
+
+        if name.eq_ignore_ascii_case("transfer-encoding") {
+            chunked = value.eq_ignore_ascii_case("chunked");
+        } else if name.eq_ignore_ascii_case("content-length") {
+            length = Some(
+                value
+                    .parse::<usize>()
+                    .map_err(|_| HttpError::Malformed("invalid content length"))?,
+            );
+        }
+    }
+
+    let body = match (chunked, length) {
+        (true, _) => decode_chunked(body)?,
+        (false, Some(length)) => body
+            .get(..length)
+            .ok_or(HttpError::Malformed("body shorter than its content length"))?
+            .to_vec(),
+        (false, None) => body.to_vec(),
+    };
+
+    Ok(Response {
+        status,
+        body: String::from_utf8_lossy(&body).into_owned(),
+    })
+}
+
+fn decode_chunked(mut raw: &[u8]) -> Result<Vec<u8>, HttpError> {
+    let mut body = Vec::new();
+
+    loop {
+        let line_end = raw
+            .windows(2)
+            .position(|window| window == b"\r\n")
+            .ok_or(HttpError::Malformed("invalid chunked body"))?;
+        let size = String::from_utf8_lossy(&raw[..line_end]);
+        // Chunk extensions after ';' carry nothing we need.
+        let size = size.split(';').next().unwrap_or_default().trim();
+        let size = usize::from_str_radix(size, 16)
+            .map_err(|_| HttpError::Malformed("invalid chunk size"))?;
+
+        raw = &raw[line_end + 2..];
+        if size == 0 {
+            return Ok(body);
+        }
+
+        let chunk = raw
+            .get(..size)
+            .ok_or(HttpError::Malformed("chunk shorter than its size"))?;
+        body.extend_from_slice(chunk);
+        raw = raw.get(size + 2..).unwrap_or_default();
+    }
+}
+
+/// Keeps the session out of the command line by passing everything as a config on stdin.
+fn send_curl(request: &Request) -> Result<Response, HttpError> {
+    let mut config = format!(
+        "url = {}\nuser-agent = {}\nheader = {}\nrequest = {}\n",
+        curl_quote(&request.url),
+        curl_quote(USER_AGENT),
+        curl_quote(&format!("Cookie: session={}", request.session)),
+        request.method,
+    );
+    if let Some(body) = &request.body {
+        config += &format!("data = {}\n", curl_quote(body));
+    }
+
+    let mut child = Command::new("curl")
+        .args(["--silent", "--show-error", "--config", "-"])
+        .args(["--max-time", &TIMEOUT.as_secs().to_string()])
+        .args(["--write-out", "\n%{http_code}"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|err| HttpError::Curl(format!("failed to run curl, needed for https: {err}")))?;
+
+    child
+        .stdin
+        .take()
+        .expect("stdin is piped")
+        .write_all(config.as_bytes())?;
+    let output = child.wait_with_output()?;
+
+    if !output.status.success() {
+        return Err(HttpError::Curl(
+            String::from_utf8_lossy(&output.stderr).trim().to_string(),
+        ));
+    }
+
+    let output = String::from_utf8_lossy(&output.stdout);
+    let (body, status) = output
+        .rsplit_once('\n')
+        .ok_or(HttpError::Malformed("curl output without status"))?;
+
+    Ok(Response {
+        status: status
+            .trim()
+            .parse()
+            .map_err(|_| HttpError::Malformed("curl output without status"))?,
+        body: body.to_string(),
+    })
+}
+
+fn curl_quote(s: &str) -> String {
+    format!("\"{}\"", s.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+fn url_encode(s: &str) -> String {
+    let mut encoded = String::with_capacity(s.len());
+
+    for byte in s.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                encoded.push(byte as char)
+            }
+            _ => encoded += &format!("%{byte:02X}"),
+        }
+    }
+
+    encoded
+}
+
+#[derive(Debug)]
+pub enum HttpError {
+    InvalidUrl(String),
+    Io(io::Error),
+    Malformed(&'static str),
+    Curl(String),
+    /// A response other than 2xx, with the first line of its body.
+    Status {
+        status: u16,
+        message: String,
+    },
+}
+
+impl Display for HttpError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            HttpError::InvalidUrl(url) => {
+                write!(f, "invalid URL {url}, expected http:// or https://")
+            }
+            HttpError::Io(err) => write!(f, "request failed: {err}"),
+            HttpError::Malformed(what) => write!(f, "malformed response: {what}"),
+            HttpError::Curl(err) => f.write_str(err),
+            HttpError::Status { status, message } => {
+                write!(f, "unexpected response {status}: {message}")
+            }
+        }
+    }
+}
+
+impl Error for HttpError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            HttpError::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for HttpError {
+    fn from(err: io::Error) -> Self {
+        HttpError::Io(err)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Client, HttpError, Response, USER_AGENT, parse_response, url_encode};
+    use crate::test_support::MockServer;
+
+    #[test]
+    fn test_parse_response() {
+        assert_eq!(
+            Response {
+                status: 200,
+                body: "1\n2\n".to_string()
+            },
+            parse_response(b"HTTP/1.1 200 OK\r\nContent-Length: 4\r\n\r\n1\n2\nextra").unwrap()
+        );
+        assert_eq!(
+            Response {
+                status: 404,
+                body: "Wikipedia".to_string()
+            },
+            parse_response(
+                b"HTTP/1.1 404 Not Found\r\ntransfer-encoding: chunked\r\n\r\n\
+                  4\r\nWiki\r\n5;ext=1\r\npedia\r\n0\r\n\r\n"
+            )
+            .unwrap()
+        );
+        assert!(matches!(
+            parse_response(b"HTTP/1.1 200 OK\r\n"),
+            Err(HttpError::Malformed(_))
+        ));
+    }
+
+    #[test]
+    fn test_url_encode() {
+        assert_eq!("a-b_1.2", url_encode("a-b_1.2"));
+        assert_eq!("1%202%26x%3D%C3%A9", url_encode("1 2&x=é"));
+    }
+
+    #[test]
+    fn test_requests() {
+        let server = MockServer::start(vec![(200, "hello".to_string()), (201, String::new())]);
+        let client = Client::new(&format!("{}/", server.url()), "secret");
+
+        assert_eq!(
+            Response {
+                status: 200,
+                body: "hello".to_string()
+            },
+            client.get("/2025/day/1/input").unwrap()
+        );
+        assert_eq!(
+            201,
+            client
+                .post_form("/answer", &[("level", "1"), ("answer", "4 2")])
+                .unwrap()
+                .status
+        );
+
+        let requests = server.requests();
+        assert!(requests[0].starts_with("GET /2025/day/1/input HTTP/1.1\r\n"));
+        assert!(requests[0].contains(&format!("User-Agent: {USER_AGENT}\r\n")));
+        assert!(requests[0].contains("Cookie: session=secret\r\n"));
+        assert!(requests[1].starts_with("POST /answer HTTP/1.1\r\n"));
+        assert!(requests[1].ends_with("\r\n\r\nlevel=1&answer=4%202"));
+    }
+
+    #[test]
+    fn test_invalid_url() {
+        assert!(matches!(
+            Client::new("ftp://example.com", "").get("/"),
+            Err(HttpError::InvalidUrl(_))
+        ));
+    }
+}
@@ -0,0 +1,152 @@
+use std::{
+    env,
+    error::Error,
+    fmt::Display,
+    fs::{self, File},
+    io::{self, BufRead, BufReader, Read},
+    path::PathBuf,
+};
+
+/// Directory with the `NN.txt` inputs, overriding `files/`.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+const DEFAULT_INPUT_DIR: &str = "files";
+
+/// `$AOC_INPUT_DIR`, or `files/` if it is not set.
+pub fn input_dir() -> PathBuf {
+    env::var_os(INPUT_DIR_VAR)
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from(DEFAULT_INPUT_DIR))
+}
+
+/// Where the puzzle input of a day is read from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    Path(PathBuf),
+    Stdin,
+}
+
+impl InputSource {
+    /// `--input <path>` (`-` for stdin) wins over `$AOC_INPUT_DIR/NN.txt`, which wins over
+    /// `files/NN.txt`.
+    pub fn resolve(explicit: Option<&str>, day: u8) -> InputSource {
+        InputSource::resolve_with(explicit, input_dir(), day)
+    }
+
+    fn resolve_with(explicit: Option<&str>, input_dir: PathBuf, day: u8) -> InputSource {
+        match explicit {
+            Some("-") => InputSource::Stdin,
+            Some(path) => InputSource::Path(PathBuf::from(path)),
+            None => InputSource::Path(input_dir.join(format!("{day:02}.txt"))),
+        }
+    }
+
+    pub fn read(&self) -> Result<String, InputError> {
+        let result = match self {
+            InputSource::Path(path) => fs::read_to_string(path),
+            InputSource::Stdin => {
+                let mut s = String::new();
+                io::stdin().read_to_string(&mut s).map(|_| s)
+            }
+        };
+
+        result.map_err(|err| self.error(err))
+    }
+
+    /// The input as a buffered reader, for days that solve it in a single pass.
+    pub fn open(&self) -> Result<Box<dyn BufRead>, InputError> {
+        match self {
+            InputSource::Path(path) => match File::open(path) {
+                Ok(file) => Ok(Box::new(BufReader::new(file))),
+                Err(err) => Err(self.error(err)),
+            },
+            InputSource::Stdin => Ok(Box::new(io::stdin().lock())),
+        }
+    }
+
+    /// A failure to read from this source.
+    pub fn error(&self, err: io::Error) -> InputError {
+        InputError {
+            source: self.clone(),
+            err,
+        }
+    }
+}
+
+impl Display for InputSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputSource::Path(path) => path.display().fmt(f),
+            InputSource::Stdin => f.write_str("<stdin>"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct InputError {
+    source: InputSource,
+    err: io::Error,
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.err.kind() == io::ErrorKind::NotFound {
+            write!(
+                f,
+                "input file {} does not exist. Pass --input <path> or set {INPUT_DIR_VAR}",
+                self.source
+            )
+        } else {
+            write!(f, "failed to read {}: {}", self.source, self.err)
+        }
+    }
+}
+
+impl Error for InputError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.err)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::InputSource;
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_resolve() {
+        let path = |s: &str| InputSource::Path(PathBuf::from(s));
+
+        assert_eq!(
+            path("files/04.txt"),
+            InputSource::resolve_with(None, "files".into(), 4)
+        );
+        assert_eq!(
+            path("inputs/04.txt"),
+            InputSource::resolve_with(None, "inputs".into(), 4)
+        );
+        assert_eq!(
+            path("my.txt"),
+            InputSource::resolve_with(Some("my.txt"), "inputs".into(), 4)
+        );
+        assert_eq!(
+            InputSource::Stdin,
+            InputSource::resolve_with(Some("-"), "files".into(), 4)
+        );
+    }
+
+    #[test]
+    fn test_missing_file() {
+        let err = InputSource::resolve_with(None, "no/such/dir".into(), 12)
+            .read()
+            .unwrap_err();
+
+        assert_eq!(
+            format!(
+                "input file {} does not exist. Pass --input <path> or set AOC_INPUT_DIR",
+                PathBuf::from("no/such/dir").join("12.txt").display()
+            ),
+            err.to_string()
+        );
+    }
+}
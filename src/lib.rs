@@ -0,0 +1,27 @@
+pub mod allocations;
+pub mod answers;
+pub mod bench;
+pub mod config;
+pub mod days;
+pub mod differential;
+pub mod export;
+pub mod fetch;
+pub mod generate;
+pub mod grid;
+pub mod http;
+pub mod input;
+pub mod parse;
+pub mod range_set;
+pub mod ratio;
+pub mod reference;
+pub mod report;
+pub mod scaffold;
+mod solution;
+pub mod stream;
+pub mod submit;
+#[cfg(test)]
+mod test_support;
+pub mod visualize;
+
+pub use parse::ParseError;
+pub use solution::{AnySolution, Part, Solution};
@@ -1,3 +1,984 @@
-fn main() {
-    println!("Hello, Advent of Code 2025!");
+use std::{
+    any::Any,
+    env,
+    error::Error,
+    fs,
+    io::{self, Write},
+    num::NonZeroUsize,
+    panic,
+    path::{Path, PathBuf},
+    process::ExitCode,
+    str::FromStr,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use aoc_2025::{
+    AnySolution, ParseError, Part,
+    answers::{Answers, Verdict, set_answer},
+    bench::{self, BenchOptions},
+    config,
+    days::{
+        self,
+        day07::{Day07, Manifold},
+    },
+    differential::{self, DiffOptions},
+    export::{self, ExportOptions},
+    fetch::{self, Fetched},
+    generate,
+    http::Client,
+    input::{self, InputSource},
+    ratio::Ratio,
+    report::{self, Format, Record},
+    scaffold, stream,
+    submit::{self, Outcome, Submissions},
+    visualize::{self, PlayOptions},
+};
+
+const USAGE: &str = "Usage:
+    aoc run <day|all> [--part <1|2>] [--input <path|->] [--format <text|json|csv>]
+            [--jobs <n>] [--visualize [--delay <ms>] [--paused]] [--stream]
+    aoc verify [day|all] [--answers <path>]
+    aoc bench [day|all] [--iterations <n>] [--warmup <n>] [--input <path|->]
+    aoc fetch <day>
+    aoc submit <day> <1|2> [--input <path|->]
+    aoc export <day> [--input <path|->] [--dir <path>] [--format <png|ppm>] [--cell <px>]
+            [--palette <name=#rrggbb,...>] [--gif [--delay <ms>]]
+    aoc gen <day> [--seed <n>] [--size <n>]
+    aoc diff [day|all] [--cases <n>] [--seed <n>] [--size <n>]
+    aoc intensity [--input <path|->] [--split <fraction>]
+    aoc graph [--input <path|->]
+    aoc new <day>
+
+Inputs are read from files/NN.txt unless AOC_INPUT_DIR points to another directory.
+--input reads a single day from <path>, or from stdin if it is -.
+--format json or csv prints one record per day and part, with times in nanoseconds.
+Built with --features count-allocations, run also reports the allocations, bytes allocated
+and peak heap of parsing and of each part.
+--jobs runs up to n days at once. A panicking day is reported and does not stop the others.
+--visualize animates days 04 and 07 in the terminal instead of printing the answers.
+--stream solves days 01 to 03 in one pass without reading the whole input into memory.
+verify compares every answer with answers.toml next to the inputs.
+bench times parsing and both parts separately.
+fetch downloads a missing input with the session token in AOC_SESSION or
+~/.config/aoc/session. AOC_BASE_URL points it to another server.
+submit posts an answer with the same session, keeps every outcome in submissions.txt
+next to the inputs and never submits an answer known to be wrong.
+export writes the frames of --visualize as images to --dir, frames/NN by default, and with
+--gif an animation.gif of all of them. Palette names are empty, paper, fading0-2, start,
+splitter, splitter-hit, beam and beam-front.
+gen prints a random input. The same seed gives the same input, by default it depends on
+the time and is printed to stderr. What --size counts depends on the day, default 10.
+diff compares the answers with brute force solutions on small generated inputs and
+shrinks the first input they disagree on.
+intensity prints the exact light arriving at the bottom of each column of day 07 when
+every splitter sends --split of a beam, 1/2 by default, to the left and the rest right.
+graph prints the start, splitters and exits of day 07 that the beams connect as a
+Graphviz DOT graph, with the rows between them on the edges.
+new creates src/days/dayNN.rs from templates/day.rs and must run in the repository root.";
+
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    match run(&args) {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(err) => {
+            eprintln!("error: {err}\n\n{USAGE}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Returns `false` if any of the requested days failed.
+fn run(args: &[String]) -> Result<bool, Box<dyn Error>> {
+    let Some((command, rest)) = args.split_first() else {
+        return Err("missing command".into());
+    };
+
+    match command.as_str() {
+        "run" => {
+            let command = RunCommand::parse(rest)?;
+            Ok(command.execute())
+        }
+        "verify" => {
+            let command = VerifyCommand::parse(rest)?;
+            Ok(command.execute())
+        }
+        "bench" => {
+            let command = BenchCommand::parse(rest)?;
+            Ok(command.execute())
+        }
+        "fetch" => {
+            let [day] = rest else {
+                return Err("fetch takes exactly one day".into());
+            };
+            fetch_day(day)?;
+            Ok(true)
+        }
+        "submit" => {
+            let command = SubmitCommand::parse(rest)?;
+            command.execute()
+        }
+        "export" => {
+            let command = ExportCommand::parse(rest)?;
+            command.execute()?;
+            Ok(true)
+        }
+        "diff" => {
+            let command = DiffCommand::parse(rest)?;
+            Ok(command.execute())
+        }
+        "gen" => {
+            generate_input(rest)?;
+            Ok(true)
+        }
+        "intensity" => {
+            print_intensities(rest)?;
+            Ok(true)
+        }
+        "graph" => {
+            print_graph(rest)?;
+            Ok(true)
+        }
+        "new" => {
+            let [day] = rest else {
+                return Err("new takes exactly one day".into());
+            };
+            new_day(day)?;
+            Ok(true)
+        }
+        _ => Err(format!("unknown command: {command}").into()),
+    }
+}
+
+struct RunCommand {
+    days: Vec<&'static dyn AnySolution>,
+    parts: Vec<Part>,
+    input: Option<String>,
+    format: Format,
+    jobs: NonZeroUsize,
+    /// Animate the day instead of printing the answers.
+    visualize: Option<PlayOptions>,
+    /// Solve in a single pass over the input instead of reading it into memory.
+    stream: bool,
+}
+
+impl RunCommand {
+    fn parse(args: &[String]) -> Result<RunCommand, Box<dyn Error>> {
+        let mut days = None;
+        let mut parts = Part::BOTH.to_vec();
+        let mut input = None;
+        let mut format = Format::default();
+        let mut jobs = NonZeroUsize::MIN;
+        let mut visualize = false;
+        let mut play = PlayOptions::default();
+        // The last option of --visualize given, which needs --visualize.
+        let mut play_option = None;
+        let mut stream = false;
+
+        let mut args = args.iter();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--part" => {
+                    let value = args.next().ok_or("--part requires a value")?;
+                    parts = vec![value.parse()?];
+                }
+                "--input" => {
+                    input = Some(args.next().ok_or("--input requires a value")?.clone());
+                }
+                "--format" => {
+                    format = args.next().ok_or("--format requires a value")?.parse()?;
+                }
+                "--jobs" => {
+                    let value = args.next().ok_or("--jobs requires a value")?;
+                    jobs = value
+                        .parse()
+                        .map_err(|_| format!("invalid number of jobs: {value}"))?;
+                }
+                "--visualize" => visualize = true,
+                "--delay" => {
+                    let value = args.next().ok_or("--delay requires a value")?;
+                    let millis = value
+                        .parse()
+                        .map_err(|_| format!("invalid delay: {value}"))?;
+                    play.delay = Duration::from_millis(millis);
+                    play_option = Some(arg);
+                }
+                "--paused" => {
+                    play.paused = true;
+                    play_option = Some(arg);
+                }
+                "--stream" => stream = true,
+                "all" => days = Some(days::ALL.to_vec()),
+                _ => days = Some(vec![parse_day(arg)?]),
+            }
+        }
+
+        let days = days.ok_or("missing day")?;
+
+        if let Some(option) = play_option
+            && !visualize
+        {
+            return Err(format!("{option} can only be used with --visualize").into());
+        }
+
+        if input.is_some() && days.len() > 1 {
+            return Err("--input can only be used with a single day".into());
+        }
+
+        if stream && let Some(solution) = days.iter().find(|s| !stream::DAYS.contains(&s.day())) {
+            return Err(format!("day {:02} can't be streamed", solution.day()).into());
+        }
+
+        if visualize {
+            let [solution] = days[..] else {
+                return Err("--visualize can only be used with a single day".into());
+            };
+
+            if !visualize::DAYS.contains(&solution.day()) {
+                let days: Vec<String> = visualize::DAYS.iter().map(|d| format!("{d:02}")).collect();
+                return Err(format!("--visualize only supports days {}", days.join(", ")).into());
+            }
+        }
+
+        Ok(RunCommand {
+            days,
+            parts,
+            input,
+            format,
+            jobs,
+            visualize: visualize.then_some(play),
+            stream,
+        })
+    }
+
+    fn execute(&self) -> bool {
+        if let Some(options) = self.visualize {
+            return match self.play(options) {
+                Ok(()) => true,
+                Err(err) => {
+                    eprintln!("error: {err}");
+                    false
+                }
+            };
+        }
+
+        let days: Vec<_> = self
+            .days
+            .iter()
+            .map(|&solution| {
+                let source = InputSource::resolve(self.input.as_deref(), solution.day());
+                (solution, source)
+            })
+            .collect();
+
+        let results = match self.stream {
+            true => days
+                .iter()
+                .map(|(solution, source)| Record::stream(solution.day(), source, &self.parts))
+                .collect(),
+            false => report::run_days(&days, &self.parts, self.jobs),
+        };
+        let records: Vec<Record> = results.iter().flatten().cloned().collect();
+
+        let written = match self.format {
+            Format::Text => {
+                for (i, day) in results.iter().enumerate() {
+                    if i > 0 {
+                        println!();
+                    }
+                    print_text(day);
+                }
+                print_failures(&results);
+                Ok(())
+            }
+            Format::Json => report::write_json(io::stdout().lock(), &records),
+            Format::Csv => report::write_csv(io::stdout().lock(), &records),
+        };
+        if let Err(err) = written {
+            eprintln!("error: failed to write results: {err}");
+            return false;
+        }
+
+        records.iter().all(|record| record.error.is_none())
+    }
+}
+
+impl RunCommand {
+    fn play(&self, options: PlayOptions) -> Result<(), Box<dyn Error>> {
+        let day = self.days[0].day();
+        let source = InputSource::resolve(self.input.as_deref(), day);
+        let text = source.read()?;
+
+        let frames = visualize::frames(day, &text)
+            .ok_or_else(|| format!("day {day:02} has no visualization"))?
+            .map_err(|err| err.with_file(source.to_string()))?;
+
+        visualize::play(&frames, options)?;
+        Ok(())
+    }
+}
+
+fn print_text(records: &[Record]) {
+    let Some(first) = records.first() else {
+        return;
+    };
+    println!("Day {:02}", first.day);
+
+    // The whole day failed if every part failed the same way.
+    if let Some(err) = &first.error
+        && records.iter().all(|record| record.error == first.error)
+    {
+        println!("error: {err}");
+        return;
+    }
+
+    for record in records {
+        match (&record.answer, &record.error) {
+            (Some(answer), _) => println!("Part {}: {answer}", record.part),
+            (None, Some(err)) => println!("Part {}: error: {err}", record.part),
+            (None, None) => println!("Part {}: not solved yet", record.part),
+        }
+    }
+    if let Some(stats) = first.parse_allocs {
+        println!("Heap:");
+        println!("  parse: {stats}");
+        for record in records {
+            if let Some(stats) = record.solve_allocs {
+                println!("  part {}: {stats}", record.part);
+            }
+        }
+    }
+}
+
+/// Lists the failed days after a run of more than one day.
+fn print_failures(results: &[Vec<Record>]) {
+    let failures: Vec<(u8, &str)> = results
+        .iter()
+        .filter_map(|records| {
+            let day = records.first()?.day;
+            Some((day, records.iter().find_map(|r| r.error.as_deref())?))
+        })
+        .collect();
+
+    if results.len() < 2 || failures.is_empty() {
+        return;
+    }
+
+    println!();
+    println!("{} of {} days failed:", failures.len(), results.len());
+    for (day, err) in failures {
+        println!("  Day {day:02}: {err}");
+    }
+}
+
+struct VerifyCommand {
+    days: Vec<&'static dyn AnySolution>,
+    answers: Option<PathBuf>,
+}
+
+impl VerifyCommand {
+    fn parse(args: &[String]) -> Result<VerifyCommand, Box<dyn Error>> {
+        let mut days = days::ALL.to_vec();
+        let mut answers = None;
+
+        let mut args = args.iter();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--answers" => {
+                    answers = Some(args.next().ok_or("--answers requires a value")?.into());
+                }
+                "all" => days = days::ALL.to_vec(),
+                _ => days = vec![parse_day(arg)?],
+            }
+        }
+
+        Ok(VerifyCommand { days, answers })
+    }
+
+    /// Fails on any mismatch or unreadable input. Missing answers are only reported.
+    fn execute(&self) -> bool {
+        let path = self
+            .answers
+            .clone()
+            .unwrap_or_else(|| input::input_dir().join("answers.toml"));
+
+        let answers = match fs::read_to_string(&path) {
+            Ok(text) => match text.parse::<Answers>() {
+                Ok(answers) => answers,
+                Err(err) => {
+                    println!("error: {}", err.with_file(path.display().to_string()));
+                    return false;
+                }
+            },
+            Err(err) if err.kind() == io::ErrorKind::NotFound => {
+                println!("warning: {} does not exist", path.display());
+                Answers::default()
+            }
+            Err(err) => {
+                println!("error: failed to read {}: {err}", path.display());
+                return false;
+            }
+        };
+
+        let (mut matched, mut mismatched, mut missing, mut failed) = (0, 0, 0, 0);
+
+        for &solution in &self.days {
+            let day = solution.day();
+
+            let input = match load_input(solution, None) {
+                Ok(input) => input,
+                Err(err) => {
+                    failed += 1;
+                    println!("Day {day:02}: error: {err}");
+                    continue;
+                }
+            };
+
+            for part in Part::BOTH {
+                let answer = match solution.solve(input.as_ref(), part) {
+                    Ok(answer) => answer,
+                    Err(err) => {
+                        failed += 1;
+                        println!("Day {day:02} part {part}: error: {err}");
+                        continue;
+                    }
+                };
+
+                let verdict = match answers.check(day, part, answer.as_deref()) {
+                    Verdict::Match => {
+                        matched += 1;
+                        "ok".to_string()
+                    }
+                    Verdict::Mismatch { expected, answer } => {
+                        mismatched += 1;
+                        let answer = answer.as_deref().unwrap_or("nothing (not solved yet)");
+                        format!("MISMATCH: expected {expected}, got {answer}")
+                    }
+                    Verdict::Missing { answer: None } => {
+                        missing += 1;
+                        "missing (not solved yet)".to_string()
+                    }
+                    Verdict::Missing {
+                        answer: Some(answer),
+                    } => {
+                        missing += 1;
+                        format!("missing, got {answer}")
+                    }
+                };
+
+                println!("Day {day:02} part {part}: {verdict}");
+            }
+        }
+
+        println!();
+        println!("{matched} ok, {mismatched} mismatched, {missing} missing, {failed} failed");
+
+        mismatched == 0 && failed == 0
+    }
+}
+
+struct BenchCommand {
+    days: Vec<&'static dyn AnySolution>,
+    options: BenchOptions,
+    input: Option<String>,
+}
+
+impl BenchCommand {
+    fn parse(args: &[String]) -> Result<BenchCommand, Box<dyn Error>> {
+        let mut days = days::ALL.to_vec();
+        let mut options = BenchOptions::default();
+        let mut input = None;
+
+        let mut args = args.iter();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--iterations" => {
+                    let value = args.next().ok_or("--iterations requires a value")?;
+                    options.iterations = value.parse()?;
+                    if options.iterations == 0 {
+                        return Err("--iterations must be at least 1".into());
+                    }
+                }
+                "--warmup" => {
+                    let value = args.next().ok_or("--warmup requires a value")?;
+                    options.warmup = value.parse()?;
+                }
+                "--input" => {
+                    input = Some(args.next().ok_or("--input requires a value")?.clone());
+                }
+                "all" => days = days::ALL.to_vec(),
+                _ => days = vec![parse_day(arg)?],
+            }
+        }
+
+        if input.is_some() && days.len() > 1 {
+            return Err("--input can only be used with a single day".into());
+        }
+
+        Ok(BenchCommand {
+            days,
+            options,
+            input,
+        })
+    }
+
+    fn execute(&self) -> bool {
+        let mut success = true;
+
+        for (i, &solution) in self.days.iter().enumerate() {
+            if i > 0 {
+                println!();
+            }
+
+            let source = InputSource::resolve(self.input.as_deref(), solution.day());
+
+            let result = match source.read() {
+                Ok(text) => bench::bench(solution, &text, self.options)
+                    .map_err(|err| err.with_file(source.to_string()).to_string()),
+                Err(err) => Err(err.to_string()),
+            };
+
+            match result {
+                Ok(result) => print!("{result}"),
+                Err(err) => {
+                    success = false;
+                    println!("Day {:02}: error: {err}", solution.day());
+                }
+            }
+        }
+
+        success
+    }
+}
+
+struct SubmitCommand {
+    solution: &'static dyn AnySolution,
+    part: Part,
+    input: Option<String>,
+}
+
+impl SubmitCommand {
+    fn parse(args: &[String]) -> Result<SubmitCommand, Box<dyn Error>> {
+        let mut positional = Vec::new();
+        let mut input = None;
+
+        let mut args = args.iter();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--input" => {
+                    input = Some(args.next().ok_or("--input requires a value")?.clone());
+                }
+                _ => positional.push(arg),
+            }
+        }
+
+        let [day, part] = positional[..] else {
+            return Err("submit takes a day and a part".into());
+        };
+
+        Ok(SubmitCommand {
+            solution: parse_day(day)?,
+            part: part.parse()?,
+            input,
+        })
+    }
+
+    /// Submits unless the answer is known to be wrong, and records what the site says.
+    fn execute(&self) -> Result<bool, Box<dyn Error>> {
+        let (day, part) = (self.solution.day(), self.part);
+
+        let input = load_input(self.solution, self.input.as_deref())?;
+        let answer = self
+            .solution
+            .solve(input.as_ref(), part)?
+            .ok_or_else(|| format!("part {part} of day {day:02} is not solved yet"))?;
+
+        let submissions_path = input::input_dir().join("submissions.txt");
+        let mut submissions: Submissions = read_or_default(&submissions_path)?;
+        let now = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs();
+
+        if let Some(refusal) = submissions.check(day, part, &answer, now) {
+            println!("Day {day:02} part {part}: not submitting {answer}: {refusal}");
+            return Ok(false);
+        }
+
+        let client = Client::new(&config::base_url(), &config::session()?);
+        let outcome = submit::submit(&client, day, part, &answer)?;
+        println!("Day {day:02} part {part}: {answer} is {outcome}");
+
+        submissions.record(day, part, &answer, &outcome, now);
+        fs::write(&submissions_path, submissions.to_string())?;
+
+        if outcome == Outcome::Correct {
+            let answers_path = input::input_dir().join("answers.toml");
+            let text = match fs::read_to_string(&answers_path) {
+                Ok(text) => text,
+                Err(err) if err.kind() == io::ErrorKind::NotFound => String::new(),
+                Err(err) => {
+                    return Err(format!("failed to read {}: {err}", answers_path.display()).into());
+                }
+            };
+
+            // Only the one answer changes, the rest of the file is kept as it is.
+            text.parse::<Answers>()
+                .map_err(|err| err.with_file(answers_path.display().to_string()))?;
+            fs::write(&answers_path, set_answer(&text, day, part, &answer))?;
+            println!("added the answer to {}", answers_path.display());
+        }
+
+        Ok(outcome == Outcome::Correct)
+    }
+}
+
+struct DiffCommand {
+    days: Vec<&'static dyn AnySolution>,
+    options: DiffOptions,
+}
+
+impl DiffCommand {
+    fn parse(args: &[String]) -> Result<DiffCommand, Box<dyn Error>> {
+        let mut days = days::ALL.to_vec();
+        let mut options = DiffOptions::default();
+
+        let mut args = args.iter();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--cases" => {
+                    let value = args.next().ok_or("--cases requires a value")?;
+                    options.cases = value
+                        .parse()
+                        .map_err(|_| format!("invalid number of cases: {value}"))?;
+                }
+                "--seed" => {
+                    let value = args.next().ok_or("--seed requires a value")?;
+                    options.seed = value
+                        .parse()
+                        .map_err(|_| format!("invalid seed: {value}"))?;
+                }
+                "--size" => {
+                    let value = args.next().ok_or("--size requires a value")?;
+                    options.size = value
+                        .parse()
+                        .ok()
+                        .filter(|&size| size > 0)
+                        .ok_or_else(|| format!("invalid size: {value}"))?;
+                }
+                "all" => days = days::ALL.to_vec(),
+                _ => days = vec![parse_day(arg)?],
+            }
+        }
+
+        Ok(DiffCommand { days, options })
+    }
+
+    fn execute(&self) -> bool {
+        // Shrinking runs a panicking solution over and over, each mismatch is reported once.
+        let hook = panic::take_hook();
+        panic::set_hook(Box::new(|_| {}));
+
+        let mut success = true;
+        for &solution in &self.days {
+            match differential::check(solution, self.options) {
+                Ok(compared) => println!(
+                    "Day {:02}: {compared} answers match the reference",
+                    solution.day()
+                ),
+                Err(mismatch) => {
+                    success = false;
+                    println!("{mismatch}");
+                }
+            }
+        }
+
+        panic::set_hook(hook);
+        success
+    }
+}
+
+struct ExportCommand {
+    day: u8,
+    input: Option<String>,
+    dir: PathBuf,
+    options: ExportOptions,
+}
+
+impl ExportCommand {
+    fn parse(args: &[String]) -> Result<ExportCommand, Box<dyn Error>> {
+        let mut day = None;
+        let mut input = None;
+        let mut dir = None;
+        let mut options = ExportOptions::default();
+        let mut gif = false;
+        let mut delay = PlayOptions::default().delay;
+
+        let mut args = args.iter();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--input" => {
+                    input = Some(args.next().ok_or("--input requires a value")?.clone());
+                }
+                "--dir" => {
+                    dir = Some(PathBuf::from(args.next().ok_or("--dir requires a value")?));
+                }
+                "--format" => {
+                    options.format = args.next().ok_or("--format requires a value")?.parse()?;
+                }
+                "--cell" => {
+                    let value = args.next().ok_or("--cell requires a value")?;
+                    options.cell = value
+                        .parse()
+                        .ok()
+                        .filter(|&cell| cell > 0)
+                        .ok_or_else(|| format!("invalid cell size: {value}"))?;
+                }
+                "--palette" => {
+                    let value = args.next().ok_or("--palette requires a value")?;
+                    options.palette = value
+                        .parse()
+                        .map_err(|err| format!("invalid palette: {err}"))?;
+                }
+                "--gif" => gif = true,
+                "--delay" => {
+                    let value = args.next().ok_or("--delay requires a value")?;
+                    let millis = value
+                        .parse()
+                        .map_err(|_| format!("invalid delay: {value}"))?;
+                    delay = Duration::from_millis(millis);
+                }
+                _ => day = Some(parse_day_number(arg)?),
+            }
+        }
+
+        let day = day.ok_or("missing day")?;
+        if !visualize::DAYS.contains(&day) {
+            let days: Vec<String> = visualize::DAYS.iter().map(|d| format!("{d:02}")).collect();
+            return Err(format!("export only supports days {}", days.join(", ")).into());
+        }
+        options.gif = gif.then_some(delay);
+
+        Ok(ExportCommand {
+            day,
+            input,
+            dir: dir.unwrap_or_else(|| PathBuf::from(format!("frames/{day:02}"))),
+            options,
+        })
+    }
+
+    fn execute(&self) -> Result<(), Box<dyn Error>> {
+        let source = InputSource::resolve(self.input.as_deref(), self.day);
+        let text = source.read()?;
+
+        let frames = visualize::frames(self.day, &text)
+            .ok_or_else(|| format!("day {:02} has no visualization", self.day))?
+            .map_err(|err| err.with_file(source.to_string()))?;
+
+        let paths = export::export(&frames, &self.options, &self.dir)
+            .map_err(|err| format!("failed to write to {}: {err}", self.dir.display()))?;
+        println!("wrote {} files to {}", paths.len(), self.dir.display());
+
+        Ok(())
+    }
+}
+
+/// Parses `path`, or starts from scratch if it doesn't exist yet.
+fn read_or_default<T>(path: &Path) -> Result<T, Box<dyn Error>>
+where
+    T: FromStr<Err = ParseError> + Default,
+{
+    match fs::read_to_string(path) {
+        Ok(text) => Ok(text
+            .parse()
+            .map_err(|err: ParseError| err.with_file(path.display().to_string()))?),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(T::default()),
+        Err(err) => Err(format!("failed to read {}: {err}", path.display()).into()),
+    }
+}
+
+fn generate_input(args: &[String]) -> Result<(), Box<dyn Error>> {
+    let mut day = None;
+    let mut seed = None;
+    let mut size = generate::DEFAULT_SIZE;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--seed" => {
+                let value = args.next().ok_or("--seed requires a value")?;
+                seed = Some(
+                    value
+                        .parse()
+                        .map_err(|_| format!("invalid seed: {value}"))?,
+                );
+            }
+            "--size" => {
+                let value = args.next().ok_or("--size requires a value")?;
+                size = value
+                    .parse()
+                    .map_err(|_| format!("invalid size: {value}"))?;
+            }
+            _ => day = Some(parse_day_number(arg)?),
+        }
+    }
+
+    let day = day.ok_or("missing day")?;
+    let seed = match seed {
+        Some(seed) => seed,
+        None => {
+            let seed = SystemTime::now().duration_since(UNIX_EPOCH)?.as_nanos() as u64;
+            eprintln!("seed {seed}");
+            seed
+        }
+    };
+
+    let mut out = io::BufWriter::new(io::stdout().lock());
+    generate::write(day, seed, size, &mut out)
+        .ok_or_else(|| format!("day {day:02} has no input generator"))??;
+    writeln!(out)?;
+    out.flush()?;
+
+    Ok(())
+}
+
+fn print_intensities(args: &[String]) -> Result<(), Box<dyn Error>> {
+    let mut input = None;
+    let mut left = Ratio::HALF;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--input" => {
+                input = Some(args.next().ok_or("--input requires a value")?.clone());
+            }
+            "--split" => {
+                let value = args.next().ok_or("--split requires a value")?;
+                left = value
+                    .parse()
+                    .ok()
+                    .filter(|&left| Ratio::ONE.checked_sub(left).is_some())
+                    .ok_or_else(|| {
+                        format!("invalid split: {value}, expected a fraction up to 1")
+                    })?;
+            }
+            _ => return Err(format!("unexpected argument: {arg}").into()),
+        }
+    }
+
+    let manifold = load_manifold(input.as_deref())?;
+    let intensities = manifold.intensities(left).ok_or(
+        "the intensities don't fit in 128 bit fractions, or the manifold has other optics than '^' or splitters side by side",
+    )?;
+
+    for (col, intensity) in intensities.iter().enumerate() {
+        if *intensity != Ratio::ZERO {
+            println!("column {col}: {intensity}");
+        }
+    }
+
+    Ok(())
+}
+
+fn print_graph(args: &[String]) -> Result<(), Box<dyn Error>> {
+    let mut input = None;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--input" => {
+                input = Some(args.next().ok_or("--input requires a value")?.clone());
+            }
+            _ => return Err(format!("unexpected argument: {arg}").into()),
+        }
+    }
+
+    let manifold = load_manifold(input.as_deref())?;
+    let graph = manifold
+        .splitter_graph()
+        .ok_or("the manifold has other optics than '^' or splitters side by side")?;
+
+    graph.write_dot(io::stdout().lock())?;
+    Ok(())
+}
+
+fn new_day(day: &str) -> Result<(), Box<dyn Error>> {
+    let day = parse_day_number(day)?;
+
+    let scaffolded = scaffold::scaffold(Path::new(""), &input::input_dir(), day)?;
+
+    println!("created {}", scaffolded.module.display());
+    match &scaffolded.input {
+        Some(input) => println!("created {}", input.display()),
+        None => println!("kept existing input for day {day:02}"),
+    }
+    println!(
+        "registered day {day:02} in {}",
+        scaffolded.registry.display()
+    );
+
+    Ok(())
+}
+
+fn fetch_day(day: &str) -> Result<(), Box<dyn Error>> {
+    let day = parse_day_number(day)?;
+    let input_dir = input::input_dir();
+
+    // Checked first so that cached inputs don't need a session.
+    if let Some(path) = fetch::cached(&input_dir, day) {
+        println!(
+            "{} already exists, not downloading it again",
+            path.display()
+        );
+        return Ok(());
+    }
+
+    let client = Client::new(&config::base_url(), &config::session()?);
+
+    match fetch::fetch(&client, &input_dir, day)? {
+        Fetched::Downloaded(path) => println!("downloaded {}", path.display()),
+        Fetched::Cached(path) => {
+            println!(
+                "{} already exists, not downloading it again",
+                path.display()
+            )
+        }
+    }
+
+    Ok(())
+}
+
+/// Reads and parses the input of a day, with errors ready to be printed.
+fn load_input(
+    solution: &dyn AnySolution,
+    explicit: Option<&str>,
+) -> Result<Box<dyn Any + Send>, String> {
+    let source = InputSource::resolve(explicit, solution.day());
+
+    match source.read() {
+        Ok(text) => solution
+            .parse(&text)
+            .map_err(|err| err.with_file(source.to_string()).to_string()),
+        Err(err) => Err(err.to_string()),
+    }
+}
+
+/// The day 07 input for the commands that work on the manifold itself.
+fn load_manifold(explicit: Option<&str>) -> Result<Manifold, Box<dyn Error>> {
+    let input = load_input(&Day07, explicit)?;
+    Ok(*input
+        .downcast::<Manifold>()
+        .expect("day 07 parses to a manifold"))
+}
+
+/// A day that may not be solved yet.
+fn parse_day_number(s: &str) -> Result<u8, Box<dyn Error>> {
+    match s.parse() {
+        Ok(day @ 1..=25) => Ok(day),
+        _ => Err(format!("invalid day: {s}").into()),
+    }
+}
+
+fn parse_day(s: &str) -> Result<&'static dyn AnySolution, Box<dyn Error>> {
+    let day: u8 = s.parse().map_err(|_| format!("invalid day: {s}"))?;
+
+    days::get(day).ok_or_else(|| format!("day {day:02} is not solved").into())
 }
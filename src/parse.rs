@@ -0,0 +1,253 @@
+use std::{error::Error, fmt::Display, str::FromStr};
+
+/// A malformed puzzle input, located by 1-based line and column.
+///
+/// Parsers of a single token or line report positions relative to it and the caller
+/// moves them to the right place with [`ParseError::shift`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub day: Option<u8>,
+    /// Overrides the default `NN.txt` name of the day's input in messages.
+    pub file: Option<String>,
+    pub line: usize,
+    pub column: usize,
+    pub kind: ParseErrorKind,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    /// `found` can't appear here.
+    UnexpectedChar { expected: &'static str, found: char },
+    /// The token or line ended too early.
+    UnexpectedEnd { expected: &'static str },
+    /// A number that doesn't fit into its type.
+    NumberTooLarge { text: String },
+    /// A line with the wrong number of items, e.g. a short grid row.
+    Count {
+        items: &'static str,
+        expected: usize,
+        found: usize,
+    },
+    /// Something the input must contain is not there.
+    Missing { expected: &'static str },
+}
+
+impl ParseError {
+    pub const fn new(line: usize, column: usize, kind: ParseErrorKind) -> ParseError {
+        ParseError {
+            day: None,
+            file: None,
+            line,
+            column,
+            kind,
+        }
+    }
+
+    /// Moves the error by `lines` down and, if it is on the first line, by `columns` right.
+    pub fn shift(mut self, lines: usize, columns: usize) -> ParseError {
+        if self.line == 1 {
+            self.column += columns;
+        }
+        self.line += lines;
+        self
+    }
+
+    pub fn with_day(mut self, day: u8) -> ParseError {
+        self.day = Some(day);
+        self
+    }
+
+    pub fn with_file(mut self, file: impl Into<String>) -> ParseError {
+        self.file = Some(file.into());
+        self
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match (&self.file, self.day) {
+            (Some(file), _) => write!(f, "{file}:")?,
+            (None, Some(day)) => write!(f, "{day:02}.txt:")?,
+            (None, None) => {}
+        }
+
+        write!(f, "{}:{}: {}", self.line, self.column, self.kind)
+    }
+}
+
+impl Error for ParseError {}
+
+impl Display for ParseErrorKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseErrorKind::UnexpectedChar { expected, found } => {
+                write!(f, "expected {expected}, found {found:?}")
+            }
+            ParseErrorKind::UnexpectedEnd { expected } => {
+                write!(f, "expected {expected}, found end of line")
+            }
+            ParseErrorKind::NumberTooLarge { text } => write!(f, "number too large: {text}"),
+            ParseErrorKind::Count {
+                items,
+                expected,
+                found,
+            } => write!(f, "expected {expected} {items}, found {found}"),
+            ParseErrorKind::Missing { expected } => write!(f, "missing {expected}"),
+        }
+    }
+}
+
+/// Parses an unsigned decimal number, pointing at the first character that is not a digit.
+pub fn parse_number<T: FromStr>(text: &str) -> Result<T, ParseError> {
+    if let Some((column, found)) = text
+        .chars()
+        .enumerate()
+        .find(|(_, ch)| !ch.is_ascii_digit())
+    {
+        return Err(ParseError::new(
+            1,
+            column + 1,
+            ParseErrorKind::UnexpectedChar {
+                expected: "digit",
+                found,
+            },
+        ));
+    }
+
+    if text.is_empty() {
+        return Err(ParseError::new(
+            1,
+            1,
+            ParseErrorKind::UnexpectedEnd { expected: "digit" },
+        ));
+    }
+
+    // Only digits are left, so the number can only be too large.
+    text.parse().map_err(|_| {
+        ParseError::new(
+            1,
+            1,
+            ParseErrorKind::NumberTooLarge {
+                text: text.to_string(),
+            },
+        )
+    })
+}
+
+/// Parses every line of `input`, locating errors in the whole input.
+pub fn parse_lines<T>(input: &str) -> Result<Vec<T>, ParseError>
+where
+    T: FromStr<Err = ParseError>,
+{
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| line.parse().map_err(|err: ParseError| err.shift(i, 0)))
+        .collect()
+}
+
+/// Splits a line at every `separator`, yielding each piece with its 0-based column.
+pub fn split_with_columns(
+    line: &str,
+    separator: impl Fn(char) -> bool,
+) -> impl Iterator<Item = (usize, &str)> {
+    let mut column = 0;
+
+    line.split(separator).map(move |piece| {
+        let start = column;
+        column += piece.chars().count() + 1;
+        (start, piece)
+    })
+}
+
+/// Whitespace separated tokens of a line with their 0-based columns.
+pub fn tokens(line: &str) -> impl Iterator<Item = (usize, &str)> {
+    split_with_columns(line, char::is_whitespace).filter(|(_, token)| !token.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ParseError, ParseErrorKind, parse_number, split_with_columns, tokens};
+
+    #[test]
+    fn test_display() {
+        let err = ParseError::new(
+            17,
+            42,
+            ParseErrorKind::UnexpectedChar {
+                expected: "digit",
+                found: 'x',
+            },
+        );
+
+        assert_eq!("17:42: expected digit, found 'x'", err.to_string());
+        assert_eq!(
+            "03.txt:17:42: expected digit, found 'x'",
+            err.clone().with_day(3).to_string()
+        );
+        assert_eq!(
+            "my/03.txt:17:42: expected digit, found 'x'",
+            err.with_day(3).with_file("my/03.txt").to_string()
+        );
+    }
+
+    #[test]
+    fn test_shift() {
+        let missing = || ParseErrorKind::Missing { expected: "x" };
+
+        assert_eq!(
+            ParseError::new(4, 12, missing()),
+            ParseError::new(1, 2, missing()).shift(3, 10)
+        );
+        assert_eq!(
+            ParseError::new(5, 2, missing()),
+            ParseError::new(2, 2, missing()).shift(3, 10)
+        );
+    }
+
+    #[test]
+    fn test_parse_number() {
+        assert_eq!(Ok(123), parse_number::<u64>("123"));
+        assert_eq!(
+            Err(ParseError::new(
+                1,
+                3,
+                ParseErrorKind::UnexpectedChar {
+                    expected: "digit",
+                    found: 'x'
+                }
+            )),
+            parse_number::<u64>("12x")
+        );
+        assert_eq!(
+            Err(ParseError::new(
+                1,
+                1,
+                ParseErrorKind::UnexpectedEnd { expected: "digit" }
+            )),
+            parse_number::<u64>("")
+        );
+        assert_eq!(
+            Err(ParseError::new(
+                1,
+                1,
+                ParseErrorKind::NumberTooLarge {
+                    text: "256".to_string()
+                }
+            )),
+            parse_number::<u8>("256")
+        );
+    }
+
+    #[test]
+    fn test_split_with_columns() {
+        assert_eq!(
+            vec![(0, "1-2"), (4, "33-44"), (10, "")],
+            split_with_columns("1-2,33-44,", |ch| ch == ',').collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![(1, "45"), (4, "64"), (9, "387")],
+            tokens(" 45 64   387").collect::<Vec<_>>()
+        );
+    }
+}
@@ -0,0 +1,337 @@
+use crate::parse::{ParseError, ParseErrorKind, parse_number, split_with_columns};
+use std::{cmp, ops::RangeInclusive, str::FromStr};
+
+/// Set of integers stored as sorted inclusive ranges that neither overlap nor touch.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct RangeSet {
+    ranges: Vec<RangeInclusive<u64>>,
+}
+
+impl RangeSet {
+    pub const fn new() -> RangeSet {
+        RangeSet { ranges: Vec::new() }
+    }
+
+    /// Normalised ranges in ascending order.
+    pub fn ranges(&self) -> &[RangeInclusive<u64>] {
+        &self.ranges
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = RangeInclusive<u64>> + '_ {
+        self.ranges.iter().cloned()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// Number of integers in the set, `None` for the set of every `u64`, which has one more
+    /// than fits in a `u64`.
+    pub fn checked_len(&self) -> Option<u64> {
+        self.ranges.iter().try_fold(0u64, |len, r| {
+            len.checked_add((r.end() - r.start()).checked_add(1)?)
+        })
+    }
+
+    pub fn min(&self) -> Option<u64> {
+        self.ranges.first().map(|r| *r.start())
+    }
+
+    pub fn max(&self) -> Option<u64> {
+        self.ranges.last().map(|r| *r.end())
+    }
+
+    /// Binary search over the ranges.
+    pub fn contains(&self, value: u64) -> bool {
+        let idx = self.ranges.partition_point(|r| *r.end() < value);
+
+        self.ranges.get(idx).is_some_and(|r| r.contains(&value))
+    }
+
+    pub fn insert(&mut self, range: RangeInclusive<u64>) {
+        if range.is_empty() {
+            return;
+        }
+
+        let mut ranges = std::mem::take(&mut self.ranges);
+        ranges.push(range);
+        *self = RangeSet::normalize(ranges);
+    }
+
+    pub fn union(&self, other: &RangeSet) -> RangeSet {
+        RangeSet::normalize(self.iter().chain(other.iter()).collect())
+    }
+
+    pub fn intersection(&self, other: &RangeSet) -> RangeSet {
+        let mut ranges = Vec::new();
+        let (mut a, mut b) = (
+            self.ranges.iter().peekable(),
+            other.ranges.iter().peekable(),
+        );
+
+        while let (Some(&x), Some(&y)) = (a.peek(), b.peek()) {
+            let start = cmp::max(*x.start(), *y.start());
+            let end = cmp::min(*x.end(), *y.end());
+
+            if start <= end {
+                ranges.push(start..=end);
+            }
+
+            // The range that ends first can't intersect anything else on the other side.
+            if x.end() < y.end() {
+                a.next();
+            } else {
+                b.next();
+            }
+        }
+
+        RangeSet { ranges }
+    }
+
+    pub fn difference(&self, other: &RangeSet) -> RangeSet {
+        self.intersection(&other.complement(0..=u64::MAX))
+    }
+
+    /// Every integer of `bounds` that is not in the set.
+    pub fn complement(&self, bounds: RangeInclusive<u64>) -> RangeSet {
+        let mut ranges = Vec::new();
+
+        if bounds.is_empty() {
+            return RangeSet { ranges };
+        }
+
+        let (lo, hi) = (*bounds.start(), *bounds.end());
+
+        // Next integer that is not covered yet. `None` once `hi` is passed.
+        let mut next = Some(lo);
+
+        for range in &self.ranges {
+            let Some(start) = next else {
+                break;
+            };
+
+            if *range.end() < start {
+                continue;
+            }
+
+            if *range.start() > hi {
+                break;
+            }
+
+            if *range.start() > start {
+                ranges.push(start..=*range.start() - 1);
+            }
+
+            next = range.end().checked_add(1);
+        }
+
+        if let Some(start) = next
+            && start <= hi
+        {
+            ranges.push(start..=hi);
+        }
+
+        RangeSet { ranges }
+    }
+
+    /// Sorts the ranges, drops empty ones and merges the overlapping and adjacent ones.
+    fn normalize(mut ranges: Vec<RangeInclusive<u64>>) -> RangeSet {
+        ranges.retain(|r| !r.is_empty());
+        ranges.sort_by_key(|r| *r.start());
+
+        let mut merged: Vec<RangeInclusive<u64>> = Vec::with_capacity(ranges.len());
+
+        for range in ranges {
+            if let Some(last) = merged.last_mut()
+                && *range.start() <= last.end().saturating_add(1)
+            {
+                *last = *last.start()..=cmp::max(*last.end(), *range.end());
+            } else {
+                merged.push(range);
+            }
+        }
+
+        RangeSet { ranges: merged }
+    }
+}
+
+impl FromIterator<RangeInclusive<u64>> for RangeSet {
+    fn from_iter<I: IntoIterator<Item = RangeInclusive<u64>>>(iter: I) -> Self {
+        RangeSet::normalize(iter.into_iter().collect())
+    }
+}
+
+/// Ranges like `11-22` separated by commas or whitespace.
+impl FromStr for RangeSet {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut ranges = Vec::new();
+
+        for (line_idx, line) in s.lines().enumerate() {
+            for (column, token) in split_with_columns(line, |ch| ch == ',' || ch.is_whitespace()) {
+                if token.is_empty() {
+                    continue;
+                }
+
+                let range = parse_range(token).map_err(|err| err.shift(line_idx, column))?;
+                ranges.push(range);
+            }
+        }
+
+        Ok(RangeSet::normalize(ranges))
+    }
+}
+
+/// Parses `start-end` into `start..=end`.
+pub fn parse_range(input: &str) -> Result<RangeInclusive<u64>, ParseError> {
+    let Some((start_str, end_str)) = input.split_once('-') else {
+        // Point at whatever is wrong with the start, or past it if it is a valid number.
+        parse_number::<u64>(input)?;
+
+        return Err(ParseError::new(
+            1,
+            input.chars().count() + 1,
+            ParseErrorKind::UnexpectedEnd { expected: "'-'" },
+        ));
+    };
+
+    let start = parse_number(start_str)?;
+    let end = parse_number(end_str).map_err(|err| err.shift(0, start_str.chars().count() + 1))?;
+
+    Ok(start..=end)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{RangeSet, parse_range};
+    use crate::parse::{ParseError, ParseErrorKind};
+
+    fn set(s: &str) -> RangeSet {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn test_parse_range() {
+        assert_eq!(11..=22, parse_range("11-22").unwrap());
+        assert_eq!(
+            5142771457..=5142940464,
+            parse_range("5142771457-5142940464").unwrap()
+        );
+        assert_eq!(
+            Err(ParseError::new(
+                1,
+                3,
+                ParseErrorKind::UnexpectedEnd { expected: "'-'" }
+            )),
+            parse_range("11")
+        );
+        assert_eq!(
+            Err(ParseError::new(
+                1,
+                4,
+                ParseErrorKind::UnexpectedChar {
+                    expected: "digit",
+                    found: '-'
+                }
+            )),
+            parse_range("1-2-3")
+        );
+    }
+
+    #[test]
+    fn test_parse_error_location() {
+        assert_eq!(
+            Err(ParseError::new(
+                2,
+                7,
+                ParseErrorKind::UnexpectedChar {
+                    expected: "digit",
+                    found: 'x'
+                }
+            )),
+            "1-2\n3-4,5-x".parse::<RangeSet>()
+        );
+    }
+
+    #[test]
+    fn test_normalize() {
+        assert_eq!(
+            vec![3..=5, 10..=20],
+            set("3-5\n10-14\n16-20\n12-18").ranges()
+        );
+        assert_eq!(vec![1..=9], set("1-3,4-6,7-9").ranges());
+        assert_eq!(vec![1..=10], set("1-10,2-3").ranges());
+        assert!(set("5-3").is_empty());
+        assert!(set("").is_empty());
+        assert_eq!(
+            vec![0..=u64::MAX],
+            set("0-1,1-18446744073709551615").ranges()
+        );
+    }
+
+    #[test]
+    fn test_checked_len() {
+        assert_eq!(Some(14), set("3-5,10-14,16-20,12-18").checked_len());
+        assert_eq!(Some(0), RangeSet::new().checked_len());
+        assert_eq!(Some(u64::MAX), set("1-18446744073709551615").checked_len());
+        assert_eq!(None, set("0-18446744073709551615").checked_len());
+        assert_eq!(None, set("0-5,6-18446744073709551615").checked_len());
+    }
+
+    #[test]
+    fn test_contains() {
+        let set = set("3-5,10-14");
+
+        assert!(!set.contains(1));
+        assert!(set.contains(3));
+        assert!(set.contains(5));
+        assert!(!set.contains(6));
+        assert!(set.contains(14));
+        assert!(!set.contains(15));
+    }
+
+    #[test]
+    fn test_insert() {
+        let mut set = set("1-2,8-9");
+        set.insert(3..=7);
+
+        assert_eq!(vec![1..=9], set.ranges());
+    }
+
+    #[test]
+    fn test_union() {
+        assert_eq!(set("1-6,10-12"), set("1-3,10-12").union(&set("4-6")));
+    }
+
+    #[test]
+    fn test_intersection() {
+        assert_eq!(
+            set("2-3,10-10,12-15"),
+            set("1-3,8-10,12-20").intersection(&set("2-5,10-15"))
+        );
+        assert!(set("1-3").intersection(&set("4-6")).is_empty());
+    }
+
+    #[test]
+    fn test_difference() {
+        assert_eq!(set("1-1,11-20"), set("1-20").difference(&set("2-10,30-40")));
+        assert_eq!(
+            set("0-18446744073709551614"),
+            set("0-18446744073709551615")
+                .difference(&set("18446744073709551615-18446744073709551615"))
+        );
+    }
+
+    #[test]
+    fn test_complement() {
+        assert_eq!(set("0-2,6-9,21-30"), set("3-5,10-20").complement(0..=30));
+        assert_eq!(set("6-9"), set("3-5,10-20").complement(4..=12));
+        assert_eq!(set("1-2"), RangeSet::new().complement(1..=2));
+        assert!(
+            set("0-18446744073709551615")
+                .complement(0..=u64::MAX)
+                .is_empty()
+        );
+    }
+}
@@ -0,0 +1,187 @@
+use crate::parse::{ParseError, ParseErrorKind, parse_number};
+use std::{fmt::Display, str::FromStr};
+
+/// Non-negative fraction in lowest terms, for results that must come out exactly the same on
+/// every run. Arithmetic is checked: `None` if a numerator or denominator overflows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Ratio {
+    numer: u128,
+    denom: u128,
+}
+
+impl Ratio {
+    pub const ZERO: Ratio = Ratio { numer: 0, denom: 1 };
+    pub const ONE: Ratio = Ratio { numer: 1, denom: 1 };
+    pub const HALF: Ratio = Ratio { numer: 1, denom: 2 };
+
+    /// `None` if `denom` is 0.
+    pub fn new(numer: u128, denom: u128) -> Option<Ratio> {
+        if denom == 0 {
+            return None;
+        }
+
+        let divisor = gcd(numer, denom);
+        Some(Ratio {
+            numer: numer / divisor,
+            denom: denom / divisor,
+        })
+    }
+
+    pub const fn numer(self) -> u128 {
+        self.numer
+    }
+
+    pub const fn denom(self) -> u128 {
+        self.denom
+    }
+
+    pub fn checked_add(self, other: Ratio) -> Option<Ratio> {
+        // Over the least common denominator, to stay small for as long as possible.
+        let divisor = gcd(self.denom, other.denom);
+        let denom = (self.denom / divisor).checked_mul(other.denom)?;
+        let left = self.numer.checked_mul(denom / self.denom)?;
+        let right = other.numer.checked_mul(denom / other.denom)?;
+
+        Ratio::new(left.checked_add(right)?, denom)
+    }
+
+    /// `None` if the result would be negative.
+    pub fn checked_sub(self, other: Ratio) -> Option<Ratio> {
+        let divisor = gcd(self.denom, other.denom);
+        let denom = (self.denom / divisor).checked_mul(other.denom)?;
+        let left = self.numer.checked_mul(denom / self.denom)?;
+        let right = other.numer.checked_mul(denom / other.denom)?;
+
+        Ratio::new(left.checked_sub(right)?, denom)
+    }
+
+    pub fn checked_mul(self, other: Ratio) -> Option<Ratio> {
+        // Cancel crosswise first, the result is then already in lowest terms.
+        let a = gcd(self.numer, other.denom);
+        let b = gcd(other.numer, self.denom);
+
+        Some(Ratio {
+            numer: (self.numer / a).checked_mul(other.numer / b)?,
+            denom: (self.denom / b).checked_mul(other.denom / a)?,
+        })
+    }
+}
+
+impl Default for Ratio {
+    fn default() -> Self {
+        Ratio::ZERO
+    }
+}
+
+impl From<u128> for Ratio {
+    fn from(n: u128) -> Self {
+        Ratio { numer: n, denom: 1 }
+    }
+}
+
+impl Display for Ratio {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.denom {
+            1 => write!(f, "{}", self.numer),
+            denom => write!(f, "{}/{denom}", self.numer),
+        }
+    }
+}
+
+/// Parses `n` or `n/d`.
+impl FromStr for Ratio {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let Some((numer, denom)) = s.split_once('/') else {
+            return parse_number::<u128>(s).map(Ratio::from);
+        };
+
+        let column = numer.chars().count() + 1;
+        let numer = parse_number(numer)?;
+        let denom = parse_number(denom).map_err(|err| err.shift(0, column))?;
+
+        Ratio::new(numer, denom).ok_or(ParseError::new(
+            1,
+            column + 1,
+            ParseErrorKind::UnexpectedChar {
+                expected: "non-zero denominator",
+                found: '0',
+            },
+        ))
+    }
+}
+
+fn gcd(mut a: u128, mut b: u128) -> u128 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+
+    // Only 0 for two zeros, and 0/0 is never built.
+    a.max(1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Ratio;
+    use crate::parse::{ParseError, ParseErrorKind};
+
+    fn ratio(s: &str) -> Ratio {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        assert_eq!(
+            ratio("5/6"),
+            ratio("1/2").checked_add(ratio("1/3")).unwrap()
+        );
+        assert_eq!(
+            ratio("1/6"),
+            ratio("1/2").checked_sub(ratio("1/3")).unwrap()
+        );
+        assert_eq!(
+            ratio("1/6"),
+            ratio("1/2").checked_mul(ratio("1/3")).unwrap()
+        );
+        assert_eq!(Ratio::ONE, ratio("2/3").checked_add(ratio("1/3")).unwrap());
+        assert_eq!(None, ratio("1/3").checked_sub(ratio("1/2")));
+
+        let tiny = Ratio::new(1, 1 << 100).unwrap();
+        assert_eq!(None, tiny.checked_mul(tiny));
+        assert_eq!(
+            Some(Ratio::new(1, 1 << 99).unwrap()),
+            tiny.checked_add(tiny)
+        );
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(Ratio::new(1, 2), Some(ratio("2/4")));
+        assert_eq!(Ratio::from(3), ratio("3"));
+        assert_eq!("1/2", ratio("2/4").to_string());
+        assert_eq!("0", ratio("0/7").to_string());
+        assert_eq!(
+            Err(ParseError::new(
+                1,
+                3,
+                ParseErrorKind::UnexpectedChar {
+                    expected: "non-zero denominator",
+                    found: '0'
+                }
+            )),
+            "1/0".parse::<Ratio>()
+        );
+        assert_eq!(
+            Err(ParseError::new(
+                1,
+                4,
+                ParseErrorKind::UnexpectedChar {
+                    expected: "digit",
+                    found: 'x'
+                }
+            )),
+            "1/2x".parse::<Ratio>()
+        );
+    }
+}
@@ -0,0 +1,427 @@
+use crate::{
+    Part, Solution,
+    days::{
+        day01::Day01, day02::Day02, day03::Day03, day04::Day04, day05::Day05, day06::Day06,
+        day07::Day07,
+    },
+};
+use std::collections::BTreeSet;
+
+/// Answer of a brute force solution that follows the puzzle text step by step, to check the
+/// real solutions against. They parse the input on their own, share no code with the real
+/// solutions and are far too slow for the real inputs.
+///
+/// `None` if there is no reference for the part, if the input is too large to brute force or
+/// if the puzzle rules it out.
+pub fn solve(day: u8, input: &str, part: Part) -> Option<String> {
+    let answer = match day {
+        Day01::DAY => dial(input, part),
+        Day02::DAY => invalid_ids(input, part),
+        Day03::DAY => joltage(input, part),
+        Day04::DAY => paper(input, part),
+        Day05::DAY => fresh(input, part),
+        Day06::DAY => worksheet(input, part),
+        Day07::DAY => splits(input, part),
+        _ => None,
+    };
+
+    answer.map(|answer| answer.to_string())
+}
+
+/// Largest number of integers a brute force walks through or keeps a bit for.
+const MAX_BRUTE_FORCE: u64 = 10_000_000;
+
+/// Turns the dial one click at a time.
+fn dial(input: &str, part: Part) -> Option<u64> {
+    let mut point = 50;
+    let mut zeros = 0;
+
+    for line in input.lines() {
+        let step = match line.get(..1)? {
+            "L" => 99,
+            "R" => 1,
+            _ => return None,
+        };
+        let clicks: u64 = line[1..].parse().ok()?;
+        if clicks > MAX_BRUTE_FORCE {
+            return None;
+        }
+
+        for _ in 0..clicks {
+            point = (point + step) % 100;
+            if part == Part::Two && point == 0 {
+                zeros += 1;
+            }
+        }
+
+        if part == Part::One && point == 0 {
+            zeros += 1;
+        }
+    }
+
+    Some(zeros)
+}
+
+/// Checks every ID of every range, counting IDs in overlapping ranges once.
+fn invalid_ids(input: &str, part: Part) -> Option<u64> {
+    let mut ids = BTreeSet::new();
+
+    for range in input.trim_end().split(',') {
+        let (start, end) = range.split_once('-')?;
+        let (start, end): (u64, u64) = (start.parse().ok()?, end.parse().ok()?);
+        if end.saturating_sub(start) > MAX_BRUTE_FORCE {
+            return None;
+        }
+        ids.extend(start..=end);
+    }
+
+    let invalid = |id: &u64| {
+        let digits = id.to_string();
+        let len = digits.len();
+
+        match part {
+            Part::One => len.is_multiple_of(2) && digits[..len / 2] == digits[len / 2..],
+            Part::Two => {
+                (1..len).any(|k| len.is_multiple_of(k) && digits == digits[..k].repeat(len / k))
+            }
+        }
+    };
+
+    Some(ids.into_iter().filter(invalid).sum())
+}
+
+/// Longest bank whose digits are chosen in every possible way.
+const MAX_BANK_LEN: usize = 20;
+
+/// Tries every way to turn on 2 or 12 batteries of each bank.
+fn joltage(input: &str, part: Part) -> Option<u64> {
+    let count = match part {
+        Part::One => 2,
+        Part::Two => 12,
+    };
+
+    let mut total = 0;
+    for line in input.lines() {
+        let digits: Vec<u64> = line
+            .chars()
+            .map(|ch| ch.to_digit(10).map(u64::from))
+            .collect::<Option<_>>()?;
+        if digits.len() < count || digits.len() > MAX_BANK_LEN {
+            return None;
+        }
+
+        total += (0u32..1 << digits.len())
+            .filter(|subset| subset.count_ones() as usize == count)
+            .map(|subset| {
+                (0..digits.len())
+                    .filter(|i| subset & 1 << i != 0)
+                    .fold(0, |joltage, i| joltage * 10 + digits[i])
+            })
+            .max()?;
+    }
+
+    Some(total)
+}
+
+/// Removes every accessible roll in rounds until none is left.
+fn paper(input: &str, part: Part) -> Option<u64> {
+    let mut grid: Vec<Vec<bool>> = input
+        .lines()
+        .map(|line| {
+            line.chars()
+                .map(|ch| match ch {
+                    '@' => Some(true),
+                    '.' => Some(false),
+                    _ => None,
+                })
+                .collect()
+        })
+        .collect::<Option<_>>()?;
+    if grid.iter().any(|row| row.len() != grid[0].len()) {
+        return None;
+    }
+
+    let mut removed = 0;
+    loop {
+        let mut accessible = Vec::new();
+
+        for (r, row) in grid.iter().enumerate() {
+            for (c, _) in row.iter().enumerate().filter(|(_, paper)| **paper) {
+                let mut neighbours = 0;
+                for (nr, nc) in (r.saturating_sub(1)..=r + 1)
+                    .flat_map(|nr| (c.saturating_sub(1)..=c + 1).map(move |nc| (nr, nc)))
+                {
+                    let paper = grid.get(nr).and_then(|row| row.get(nc)) == Some(&true);
+                    if (nr, nc) != (r, c) && paper {
+                        neighbours += 1;
+                    }
+                }
+
+                if neighbours < 4 {
+                    accessible.push((r, c));
+                }
+            }
+        }
+
+        if part == Part::One || accessible.is_empty() {
+            return Some(removed + accessible.len() as u64);
+        }
+
+        removed += accessible.len() as u64;
+        for (r, c) in accessible {
+            grid[r][c] = false;
+        }
+    }
+}
+
+/// Marks every fresh ID in a bitmap.
+fn fresh(input: &str, part: Part) -> Option<u64> {
+    let (ranges, ids) = input.split_once("\n\n").unwrap_or((input, ""));
+
+    let ranges: Vec<(u64, u64)> = ranges
+        .lines()
+        .map(|line| {
+            let (start, end) = line.split_once('-')?;
+            Some((start.parse().ok()?, end.parse().ok()?))
+        })
+        .collect::<Option<_>>()?;
+    let ids: Vec<u64> = ids
+        .lines()
+        .map(|line| line.parse().ok())
+        .collect::<Option<_>>()?;
+
+    let low = ranges.iter().map(|&(start, _)| start).min().unwrap_or(0);
+    let high = ranges.iter().map(|&(_, end)| end).max().unwrap_or(0);
+    if high.saturating_sub(low) > MAX_BRUTE_FORCE {
+        return None;
+    }
+
+    let mut bitmap = vec![false; (high.saturating_sub(low) + 1) as usize];
+    for (start, end) in ranges {
+        for id in start..=end {
+            bitmap[(id - low) as usize] = true;
+        }
+    }
+
+    let is_fresh = |id: u64| id >= low && bitmap.get((id - low) as usize) == Some(&true);
+
+    match part {
+        Part::One => Some(ids.into_iter().filter(|&id| is_fresh(id)).count() as u64),
+        Part::Two => Some(bitmap.iter().filter(|&&fresh| fresh).count() as u64),
+    }
+}
+
+/// Reads the numbers row by row for part 1 and column by column for part 2. `None` if a
+/// result doesn't fit in 64 bits.
+fn worksheet(input: &str, part: Part) -> Option<u64> {
+    let lines: Vec<Vec<char>> = input.lines().map(|line| line.chars().collect()).collect();
+    let (signs, rows) = lines.split_last()?;
+
+    let problems: Vec<(char, Vec<u64>)> = match part {
+        Part::One => {
+            let rows: Vec<Vec<u64>> = rows
+                .iter()
+                .map(|row| {
+                    String::from_iter(row)
+                        .split_whitespace()
+                        .map(|number| number.parse().ok())
+                        .collect()
+                })
+                .collect::<Option<_>>()?;
+            let signs: Vec<char> = signs.iter().copied().filter(|ch| *ch != ' ').collect();
+            if rows.iter().any(|row| row.len() != signs.len()) {
+                return None;
+            }
+
+            signs
+                .into_iter()
+                .enumerate()
+                .map(|(i, sign)| (sign, rows.iter().map(|row| row[i]).collect()))
+                .collect()
+        }
+        Part::Two => {
+            let width = lines.iter().map(Vec::len).max()?;
+            let at = |line: &Vec<char>, col: usize| line.get(col).copied().unwrap_or(' ');
+            let mut problems = Vec::new();
+            let mut current: Option<(char, Vec<u64>)> = None;
+
+            // A blank column past the end closes the last problem.
+            for col in 0..=width {
+                if lines.iter().all(|line| at(line, col) == ' ') {
+                    problems.extend(current.take());
+                    continue;
+                }
+
+                let digits: String = rows.iter().map(|row| at(row, col)).collect();
+                let number = digits.trim().parse().ok()?;
+                let (_, numbers) = current.get_or_insert((at(signs, col), Vec::new()));
+                numbers.push(number);
+            }
+
+            problems
+        }
+    };
+
+    problems
+        .into_iter()
+        .map(|(sign, numbers)| match sign {
+            '+' => numbers.into_iter().try_fold(0u64, u64::checked_add),
+            '*' => numbers.into_iter().try_fold(1u64, u64::checked_mul),
+            _ => None,
+        })
+        .try_fold(0u64, |total, result| total.checked_add(result?))
+}
+
+/// Moves the beams down one row at a time. Splitters next to each other, even diagonally,
+/// never happen in the puzzle and have no reference answer.
+fn splits(input: &str, part: Part) -> Option<u64> {
+    let grid: Vec<Vec<char>> = input.lines().map(|line| line.chars().collect()).collect();
+    let splitter = |r: usize, c: usize| grid.get(r).and_then(|row| row.get(c)) == Some(&'^');
+
+    for (r, row) in grid.iter().enumerate() {
+        for (c, &ch) in row.iter().enumerate() {
+            if !matches!(ch, '.' | '^' | 'S') || (ch == 'S' && r > 0) {
+                return None;
+            }
+
+            let crowded = (r.saturating_sub(1)..=r + 1)
+                .flat_map(|nr| (c.saturating_sub(1)..=c + 1).map(move |nc| (nr, nc)))
+                .any(|(nr, nc)| (nr, nc) != (r, c) && splitter(nr, nc));
+            if ch == '^' && crowded {
+                return None;
+            }
+        }
+    }
+
+    let first = grid.first()?;
+    let start = first.iter().position(|&ch| ch == 'S')?;
+    if first.iter().filter(|&&ch| ch == 'S').count() > 1 {
+        return None;
+    }
+
+    match part {
+        Part::One => Some(beam_splits(&grid, start)),
+        Part::Two => timelines(&grid, start),
+    }
+}
+
+fn beam_splits(grid: &[Vec<char>], start: usize) -> u64 {
+    let mut beams = BTreeSet::from([start]);
+    let mut splits = 0;
+
+    for row in &grid[1..] {
+        let mut next = BTreeSet::new();
+
+        for col in beams {
+            if row.get(col) == Some(&'^') {
+                splits += 1;
+                next.extend(col.checked_sub(1));
+                next.extend(Some(col + 1).filter(|&right| right < row.len()));
+            } else {
+                next.insert(col);
+            }
+        }
+
+        beams = next;
+    }
+
+    splits
+}
+
+/// Follows every timeline on its own, `None` if there are too many.
+fn timelines(grid: &[Vec<char>], start: usize) -> Option<u64> {
+    let mut particles = vec![(0, start)];
+    let mut timelines = 0;
+
+    while let Some((row, col)) = particles.pop() {
+        let Some(next) = grid.get(row + 1) else {
+            timelines += 1;
+            if timelines > MAX_BRUTE_FORCE {
+                return None;
+            }
+            continue;
+        };
+
+        if next.get(col) == Some(&'^') {
+            particles.extend(col.checked_sub(1).map(|left| (row + 1, left)));
+            particles.extend(
+                Some(col + 1)
+                    .filter(|&right| right < next.len())
+                    .map(|right| (row + 1, right)),
+            );
+        } else {
+            particles.push((row + 1, col));
+        }
+    }
+
+    Some(timelines)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::solve;
+    use crate::Part;
+
+    fn both(day: u8, input: &str) -> [Option<String>; 2] {
+        Part::BOTH.map(|part| solve(day, input, part))
+    }
+
+    fn answers(part1: u64, part2: u64) -> [Option<String>; 2] {
+        [Some(part1.to_string()), Some(part2.to_string())]
+    }
+
+    #[test]
+    fn test_examples() {
+        assert_eq!(
+            answers(3, 6),
+            both(1, "L68\nL30\nR48\nL5\nR60\nL55\nL1\nL99\nR14\nL82")
+        );
+        assert_eq!(answers(0, 10), both(1, "R1000"));
+        assert_eq!(
+            answers(1227775554, 4174379265),
+            both(
+                2,
+                "11-22,95-115,998-1012,1188511880-1188511890,222220-222224,1698522-1698528,\
+                 446443-446449,38593856-38593862,565653-565659,824824821-824824827,\
+                 2121212118-2121212124"
+            )
+        );
+        assert_eq!(
+            answers(357, 3121910778619),
+            both(
+                3,
+                "987654321111111\n811111111111119\n234234234234278\n818181911112111"
+            )
+        );
+        assert_eq!(
+            answers(13, 43),
+            both(
+                4,
+                "..@@.@@@@.\n@@@.@.@.@@\n@@@@@.@.@@\n@.@@@@..@.\n@@.@@@@.@@\n\
+                 .@@@@@@@.@\n.@.@.@.@@@\n@.@@@.@@@@\n.@@@@@@@@.\n@.@.@@@.@."
+            )
+        );
+        assert_eq!(
+            answers(3, 14),
+            both(5, "3-5\n10-14\n16-20\n12-18\n\n1\n5\n8\n11\n17\n32")
+        );
+        assert_eq!(
+            answers(4277556, 3263827),
+            both(
+                6,
+                "123 328  51 64 \n 45 64  387 23 \n  6 98  215 314\n*   +   *   +  "
+            )
+        );
+        assert_eq!(answers(2, 3), both(7, "..S..\n.....\n..^..\n.....\n.^..."));
+    }
+
+    #[test]
+    fn test_ruled_out() {
+        assert_eq!(None, solve(3, "1234", Part::Two));
+        assert_eq!(None, solve(3, "1x", Part::One));
+        assert_eq!(Some("10".to_string()), solve(3, "10", Part::One));
+        assert_eq!(None, solve(5, "1-100000000000\n\n5", Part::Two));
+        assert_eq!(None, solve(7, "S.\n^^", Part::One));
+        assert_eq!(None, solve(25, "", Part::One));
+    }
+}
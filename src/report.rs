@@ -0,0 +1,504 @@
+use crate::{
+    AnySolution, Part,
+    allocations::{self, AllocStats},
+    input::InputSource,
+    stream::{self, StreamError},
+};
+use std::{
+    any::Any,
+    error::Error,
+    fmt::Write as _,
+    io::{self, Write},
+    num::NonZeroUsize,
+    panic::{self, AssertUnwindSafe},
+    str::FromStr,
+    sync::{
+        Mutex,
+        atomic::{AtomicUsize, Ordering},
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+/// Output format of `aoc run`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Format {
+    #[default]
+    Text,
+    Json,
+    Csv,
+}
+
+impl FromStr for Format {
+    type Err = Box<dyn Error>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            _ => Err(format!("invalid format: {s}, expected text, json or csv").into()),
+        }
+    }
+}
+
+/// Outcome of one part of one day.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Record {
+    pub day: u8,
+    pub part: Part,
+    /// `None` if the part is not solved yet, has no answer or the input failed.
+    pub answer: Option<String>,
+    /// Shared by both parts of a day. `None` if the input could not be read.
+    pub parse_time: Option<Duration>,
+    pub solve_time: Option<Duration>,
+    /// Heap use of the phases, only with the `count-allocations` feature.
+    pub parse_allocs: Option<AllocStats>,
+    pub solve_allocs: Option<AllocStats>,
+    /// Why the input could not be read or parsed, why the part has no answer for it, or why
+    /// the day panicked.
+    pub error: Option<String>,
+}
+
+impl Record {
+    /// Reads and parses the input once and solves `parts` on it, timing each phase.
+    pub fn run(solution: &dyn AnySolution, source: &InputSource, parts: &[Part]) -> Vec<Record> {
+        let record = |part, parse_time, parse_allocs, error| Record {
+            day: solution.day(),
+            part,
+            answer: None,
+            parse_time,
+            solve_time: None,
+            parse_allocs,
+            solve_allocs: None,
+            error,
+        };
+        let failed = |parse_time, parse_allocs, error: String| {
+            parts
+                .iter()
+                .map(|&part| record(part, parse_time, parse_allocs, Some(error.clone())))
+                .collect()
+        };
+
+        let text = match source.read() {
+            Ok(text) => text,
+            Err(err) => return failed(None, None, err.to_string()),
+        };
+
+        let start = Instant::now();
+        let (parsed, parse_allocs) = allocations::measure(|| solution.parse(&text));
+        let parse_time = start.elapsed();
+
+        let input = match parsed {
+            Ok(input) => input,
+            Err(err) => {
+                let err = err.with_file(source.to_string());
+                return failed(Some(parse_time), parse_allocs, err.to_string());
+            }
+        };
+
+        parts
+            .iter()
+            .map(|&part| {
+                let start = Instant::now();
+                let (answer, solve_allocs) =
+                    allocations::measure(|| solution.solve(input.as_ref(), part));
+                let solve_time = start.elapsed();
+                let (answer, error) = match answer {
+                    Ok(answer) => (answer, None),
+                    Err(err) => (None, Some(err)),
+                };
+
+                Record {
+                    answer,
+                    solve_time: Some(solve_time),
+                    solve_allocs,
+                    ..record(part, Some(parse_time), parse_allocs, error)
+                }
+            })
+            .collect()
+    }
+
+    /// Like [`Record::run`] for one of the [`stream::DAYS`], which solve both parts in a
+    /// single pass without reading the whole input into memory. That pass counts as parsing.
+    pub fn stream(day: u8, source: &InputSource, parts: &[Part]) -> Vec<Record> {
+        let start = Instant::now();
+        let (answers, parse_allocs) = allocations::measure(|| {
+            source
+                .open()
+                .map_err(|err| err.to_string())
+                .and_then(|reader| match stream::solve(day, reader) {
+                    Some(Ok(answers)) => Ok(answers),
+                    Some(Err(StreamError::Io(err))) => Err(source.error(err).to_string()),
+                    Some(Err(StreamError::Parse(err))) => {
+                        Err(err.with_file(source.to_string()).to_string())
+                    }
+                    None => Err(format!("day {day:02} can't be streamed")),
+                })
+        });
+        let parse_time = start.elapsed();
+
+        parts
+            .iter()
+            .map(|&part| {
+                let (answer, error) = match &answers {
+                    Ok(answers) => match &answers[part.number() as usize - 1] {
+                        Ok(answer) => (answer.clone(), None),
+                        Err(err) => (None, Some(err.clone())),
+                    },
+                    Err(err) => (None, Some(err.clone())),
+                };
+
+                Record {
+                    day,
+                    part,
+                    answer,
+                    parse_time: Some(parse_time),
+                    solve_time: None,
+                    parse_allocs,
+                    solve_allocs: None,
+                    error,
+                }
+            })
+            .collect()
+    }
+
+    /// Like [`Record::run`], but a panic becomes an error of every part of the day.
+    pub fn run_isolated(
+        solution: &dyn AnySolution,
+        source: &InputSource,
+        parts: &[Part],
+    ) -> Vec<Record> {
+        panic::catch_unwind(AssertUnwindSafe(|| Record::run(solution, source, parts)))
+            .unwrap_or_else(|payload| {
+                let error = format!("panicked: {}", panic_message(payload.as_ref()));
+
+                parts
+                    .iter()
+                    .map(|&part| Record {
+                        day: solution.day(),
+                        part,
+                        answer: None,
+                        parse_time: None,
+                        solve_time: None,
+                        parse_allocs: None,
+                        solve_allocs: None,
+                        error: Some(error.clone()),
+                    })
+                    .collect()
+            })
+    }
+}
+
+pub(crate) fn panic_message(payload: &(dyn Any + Send)) -> &str {
+    payload
+        .downcast_ref::<&str>()
+        .copied()
+        .or_else(|| payload.downcast_ref::<String>().map(String::as_str))
+        .unwrap_or("unknown cause")
+}
+
+/// Runs every day on up to `jobs` threads with [`Record::run_isolated`], returning the
+/// records of each day in the order of `days`.
+pub fn run_days(
+    days: &[(&dyn AnySolution, InputSource)],
+    parts: &[Part],
+    jobs: NonZeroUsize,
+) -> Vec<Vec<Record>> {
+    let next = AtomicUsize::new(0);
+    let results: Vec<Mutex<Vec<Record>>> = days.iter().map(|_| Mutex::default()).collect();
+
+    thread::scope(|scope| {
+        for _ in 0..jobs.get().min(days.len()) {
+            scope.spawn(|| {
+                loop {
+                    let i = next.fetch_add(1, Ordering::Relaxed);
+                    let Some(&(solution, ref source)) = days.get(i) else {
+                        break;
+                    };
+
+                    let records = Record::run_isolated(solution, source, parts);
+                    *results[i].lock().unwrap_or_else(|err| err.into_inner()) = records;
+                }
+            });
+        }
+    });
+
+    results
+        .into_iter()
+        .map(|records| records.into_inner().unwrap_or_else(|err| err.into_inner()))
+        .collect()
+}
+
+/// A JSON array with one object per record and line. Times are in nanoseconds, heap use is
+/// an object with the fields of [`AllocStats`], missing values are `null`.
+pub fn write_json(mut w: impl Write, records: &[Record]) -> io::Result<()> {
+    writeln!(w, "[")?;
+
+    for (i, record) in records.iter().enumerate() {
+        let separator = if i + 1 < records.len() { "," } else { "" };
+
+        writeln!(
+            w,
+            "  {{\"day\": {}, \"part\": {}, \"answer\": {}, \"parse_ns\": {}, \"solve_ns\": {}, \"parse_allocs\": {}, \"solve_allocs\": {}, \"error\": {}}}{separator}",
+            record.day,
+            record.part,
+            json_string(record.answer.as_deref()),
+            json_nanos(record.parse_time),
+            json_nanos(record.solve_time),
+            json_allocs(record.parse_allocs),
+            json_allocs(record.solve_allocs),
+            json_string(record.error.as_deref()),
+        )?;
+    }
+
+    writeln!(w, "]")
+}
+
+fn json_nanos(duration: Option<Duration>) -> String {
+    duration.map_or("null".to_string(), |d| d.as_nanos().to_string())
+}
+
+fn json_allocs(stats: Option<AllocStats>) -> String {
+    stats.map_or("null".to_string(), |stats| {
+        format!(
+            "{{\"allocations\": {}, \"bytes\": {}, \"peak\": {}}}",
+            stats.allocations, stats.bytes, stats.peak
+        )
+    })
+}
+
+fn json_string(s: Option<&str>) -> String {
+    let Some(s) = s else {
+        return "null".to_string();
+    };
+
+    let mut json = String::with_capacity(s.len() + 2);
+    json.push('"');
+    for ch in s.chars() {
+        match ch {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            ch if ch.is_control() => write!(json, "\\u{:04x}", ch as u32).unwrap(),
+            ch => json.push(ch),
+        }
+    }
+    json.push('"');
+
+    json
+}
+
+/// CSV with a header line. Times are in nanoseconds, missing values are empty.
+pub fn write_csv(mut w: impl Write, records: &[Record]) -> io::Result<()> {
+    writeln!(
+        w,
+        "day,part,answer,parse_ns,solve_ns,parse_allocations,parse_bytes,parse_peak,solve_allocations,solve_bytes,solve_peak,error"
+    )?;
+
+    for record in records {
+        let nanos = |d: Option<Duration>| d.map_or(String::new(), |d| d.as_nanos().to_string());
+        let allocs = |stats: Option<AllocStats>| {
+            stats.map_or(",,".to_string(), |stats| {
+                format!("{},{},{}", stats.allocations, stats.bytes, stats.peak)
+            })
+        };
+
+        writeln!(
+            w,
+            "{},{},{},{},{},{},{},{}",
+            record.day,
+            record.part,
+            csv_field(record.answer.as_deref().unwrap_or_default()),
+            nanos(record.parse_time),
+            nanos(record.solve_time),
+            allocs(record.parse_allocs),
+            allocs(record.solve_allocs),
+            csv_field(record.error.as_deref().unwrap_or_default()),
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Quotes fields with separators, quotes or line breaks, doubling the quotes.
+fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Format, Record, run_days, write_csv, write_json};
+    use crate::{
+        AnySolution, ParseError, Part, Solution,
+        allocations::{self, AllocStats},
+        days::{day01::Day01, day07::Day07},
+        input::InputSource,
+    };
+    use std::{env, fs, num::NonZeroUsize, path::PathBuf, process, time::Duration};
+
+    struct Panicking;
+
+    impl Solution for Panicking {
+        const DAY: u8 = 99;
+
+        type Input = ();
+        type Output = u8;
+
+        fn parse(_input: &str) -> Result<(), ParseError> {
+            Ok(())
+        }
+
+        fn part1(_input: &()) -> Option<u8> {
+            Some(1)
+        }
+
+        fn part2(_input: &()) -> Option<u8> {
+            panic!("no part 2 for {}", Self::DAY)
+        }
+    }
+
+    fn records() -> Vec<Record> {
+        vec![
+            Record {
+                day: 1,
+                part: Part::One,
+                answer: Some("989".to_string()),
+                parse_time: Some(Duration::from_nanos(1500)),
+                solve_time: Some(Duration::from_nanos(20)),
+                parse_allocs: Some(AllocStats {
+                    allocations: 3,
+                    bytes: 200,
+                    peak: 150,
+                }),
+                solve_allocs: Some(AllocStats::default()),
+                error: None,
+            },
+            Record {
+                day: 2,
+                part: Part::Two,
+                answer: None,
+                parse_time: None,
+                solve_time: None,
+                parse_allocs: None,
+                solve_allocs: None,
+                error: Some("02.txt:1:3: expected digit, found '\"'".to_string()),
+            },
+        ]
+    }
+
+    #[test]
+    fn test_parse_format() {
+        assert_eq!(Format::Json, "json".parse().unwrap());
+        assert_eq!(Format::Csv, "csv".parse().unwrap());
+        assert!("xml".parse::<Format>().is_err());
+    }
+
+    #[test]
+    fn test_write_json() {
+        let mut out = Vec::new();
+        write_json(&mut out, &records()).unwrap();
+
+        assert_eq!(
+            r#"[
+  {"day": 1, "part": 1, "answer": "989", "parse_ns": 1500, "solve_ns": 20, "parse_allocs": {"allocations": 3, "bytes": 200, "peak": 150}, "solve_allocs": {"allocations": 0, "bytes": 0, "peak": 0}, "error": null},
+  {"day": 2, "part": 2, "answer": null, "parse_ns": null, "solve_ns": null, "parse_allocs": null, "solve_allocs": null, "error": "02.txt:1:3: expected digit, found '\"'"}
+]
+"#,
+            String::from_utf8(out).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_write_csv() {
+        let mut out = Vec::new();
+        write_csv(&mut out, &records()).unwrap();
+
+        assert_eq!(
+            r#"day,part,answer,parse_ns,solve_ns,parse_allocations,parse_bytes,parse_peak,solve_allocations,solve_bytes,solve_peak,error
+1,1,989,1500,20,3,200,150,0,0,0,
+2,2,,,,,,,,,,"02.txt:1:3: expected digit, found '""'"
+"#,
+            String::from_utf8(out).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_run() {
+        let path = env::temp_dir().join(format!("aoc-report-{}.txt", process::id()));
+        let source = InputSource::Path(path.clone());
+
+        fs::write(&path, "..S..\n..^..\n.....").unwrap();
+        let records = Record::run(&Day07, &source, &Part::BOTH);
+
+        assert_eq!(2, records.len());
+        assert_eq!(Some("1"), records[0].answer.as_deref());
+        assert_eq!(Some("2"), records[1].answer.as_deref());
+        assert!(
+            records
+                .iter()
+                .all(|r| r.solve_time.is_some() && r.error.is_none())
+        );
+        assert_eq!(allocations::ENABLED, records[0].parse_allocs.is_some());
+
+        fs::write(&path, "..S..\n..x..").unwrap();
+        let records = Record::run(&Day07, &source, &[Part::One]);
+
+        assert_eq!(
+            Some(format!(
+                "{}:2:3: expected '.', 'S', '^', ':', '/', '\\', '-', '|' or '#', found 'x'",
+                path.display()
+            )),
+            records[0].error
+        );
+        assert!(records[0].parse_time.is_some());
+
+        fs::remove_file(&path).unwrap();
+        let records = Record::run(&Day07, &source, &[Part::One]);
+
+        assert_eq!(None, records[0].parse_time);
+        assert!(records[0].error.is_some());
+    }
+
+    #[test]
+    fn test_run_days() {
+        let path = env::temp_dir().join(format!("aoc-run-days-{}.txt", process::id()));
+        fs::write(&path, "L68\nR18").unwrap();
+        let source = || InputSource::Path(path.clone());
+
+        let days: Vec<(&dyn AnySolution, InputSource)> = vec![
+            (&Day01, source()),
+            (&Panicking, source()),
+            (&Day01, InputSource::Path(PathBuf::from("no/such/01.txt"))),
+            (&Day01, source()),
+        ];
+        let results = run_days(&days, &Part::BOTH, NonZeroUsize::new(3).unwrap());
+        fs::remove_file(&path).unwrap();
+
+        let answers: Vec<Vec<Option<&str>>> = results
+            .iter()
+            .map(|records| records.iter().map(|r| r.answer.as_deref()).collect())
+            .collect();
+        assert_eq!(
+            vec![
+                vec![Some("1"), Some("2")],
+                vec![None, None],
+                vec![None, None],
+                vec![Some("1"), Some("2")],
+            ],
+            answers
+        );
+        assert_eq!(
+            Some("panicked: no part 2 for 99"),
+            results[1][1].error.as_deref()
+        );
+        assert_eq!(99, results[1][0].day);
+        assert!(results[2][0].error.is_some());
+    }
+}
@@ -0,0 +1,281 @@
+use std::{
+    error::Error,
+    fmt::Display,
+    fs,
+    io::{self, ErrorKind},
+    path::{Path, PathBuf},
+};
+
+const TEMPLATE: &str = include_str!("../templates/day.rs");
+
+/// Files touched by [`scaffold`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Scaffold {
+    pub module: PathBuf,
+    /// `None` if the input already existed and was kept.
+    pub input: Option<PathBuf>,
+    pub registry: PathBuf,
+}
+
+/// Creates `src/days/dayNN.rs` under `root` from the template, registers it in
+/// `src/days/mod.rs` and creates an empty `NN.txt` in `input_dir` unless there is one.
+///
+/// Refuses to touch anything if the day already exists.
+pub fn scaffold(root: &Path, input_dir: &Path, day: u8) -> Result<Scaffold, ScaffoldError> {
+    let days_dir = root.join("src").join("days");
+    let module = days_dir.join(format!("day{day:02}.rs"));
+    let registry = days_dir.join("mod.rs");
+
+    if module.exists() {
+        return Err(ScaffoldError::Exists(module));
+    }
+
+    let registered = register(&read(&registry)?, day).ok_or(ScaffoldError::Registered {
+        registry: registry.clone(),
+        day,
+    })?;
+
+    write(&module, &render(day))?;
+    write(&registry, &registered)?;
+
+    let input = input_dir.join(format!("{day:02}.txt"));
+    let input = if input.exists() {
+        None
+    } else {
+        fs::create_dir_all(input_dir).map_err(|err| ScaffoldError::io(input_dir, err))?;
+        write(&input, "")?;
+        Some(input)
+    };
+
+    Ok(Scaffold {
+        module,
+        input,
+        registry,
+    })
+}
+
+fn render(day: u8) -> String {
+    TEMPLATE
+        .replace("{{day}}", &format!("{day:02}"))
+        .replace("{{number}}", &day.to_string())
+}
+
+/// Adds `pub mod dayNN;` and `&dayNN::DayNN` to the registry, keeping both sorted, and
+/// grows `ALL` by one. `None` if the day is already there.
+fn register(registry: &str, day: u8) -> Option<String> {
+    let module = format!("pub mod day{day:02};");
+    let entry = format!("    &day{day:02}::Day{day:02},");
+
+    if registry.lines().any(|line| line == module) {
+        return None;
+    }
+
+    let mut lines: Vec<String> = registry.lines().map(str::to_string).collect();
+
+    insert_sorted(&mut lines, |line| line.starts_with("pub mod day"), module);
+    insert_sorted(&mut lines, |line| line.starts_with("    &day"), entry);
+
+    let count = lines
+        .iter()
+        .filter(|line| line.starts_with("pub mod day"))
+        .count();
+    for line in &mut lines {
+        if let Some((before, _)) = line.split_once("[&dyn AnySolution; ") {
+            *line = format!("{before}[&dyn AnySolution; {count}] = [");
+        }
+    }
+
+    let mut registered = lines.join("\n");
+    registered.push('\n');
+    Some(registered)
+}
+
+/// Inserts `new` among the consecutive lines matching `is_item`, before the first larger one.
+/// Zero-padded day numbers make string order the day order.
+fn insert_sorted(lines: &mut Vec<String>, is_item: impl Fn(&str) -> bool, new: String) {
+    let first = lines.iter().position(|line| is_item(line));
+    let Some(first) = first else {
+        lines.push(new);
+        return;
+    };
+
+    let end = lines[first..]
+        .iter()
+        .position(|line| !is_item(line))
+        .map_or(lines.len(), |len| first + len);
+    let at = lines[first..end]
+        .iter()
+        .position(|line| *line > new)
+        .map_or(end, |i| first + i);
+
+    lines.insert(at, new);
+}
+
+fn read(path: &Path) -> Result<String, ScaffoldError> {
+    fs::read_to_string(path).map_err(|err| ScaffoldError::io(path, err))
+}
+
+fn write(path: &Path, contents: &str) -> Result<(), ScaffoldError> {
+    fs::write(path, contents).map_err(|err| ScaffoldError::io(path, err))
+}
+
+#[derive(Debug)]
+pub enum ScaffoldError {
+    /// The module of the day is already there.
+    Exists(PathBuf),
+    /// The registry already lists the day.
+    Registered {
+        registry: PathBuf,
+        day: u8,
+    },
+    Io {
+        path: PathBuf,
+        err: io::Error,
+    },
+}
+
+impl ScaffoldError {
+    fn io(path: &Path, err: io::Error) -> ScaffoldError {
+        ScaffoldError::Io {
+            path: path.to_path_buf(),
+            err,
+        }
+    }
+}
+
+impl Display for ScaffoldError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ScaffoldError::Exists(path) => {
+                write!(f, "{} already exists, not overwriting it", path.display())
+            }
+            ScaffoldError::Registered { registry, day } => {
+                write!(
+                    f,
+                    "day {day:02} is already registered in {}",
+                    registry.display()
+                )
+            }
+            ScaffoldError::Io { path, err } if err.kind() == ErrorKind::NotFound => {
+                write!(
+                    f,
+                    "{} does not exist. Run aoc new from the repository root",
+                    path.display()
+                )
+            }
+            ScaffoldError::Io { path, err } => write!(f, "{}: {err}", path.display()),
+        }
+    }
+}
+
+impl Error for ScaffoldError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ScaffoldError::Io { err, .. } => Some(err),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ScaffoldError, register, render, scaffold};
+    use std::{env, fs, process};
+
+    const REGISTRY: &str = r#"use crate::AnySolution;
+
+pub mod day01;
+pub mod day03;
+
+/// Every solved day, in order.
+pub const ALL: [&dyn AnySolution; 2] = [
+    &day01::Day01,
+    &day03::Day03,
+];
+"#;
+
+    #[test]
+    fn test_register() {
+        assert_eq!(
+            Some(
+                r#"use crate::AnySolution;
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+
+/// Every solved day, in order.
+pub const ALL: [&dyn AnySolution; 3] = [
+    &day01::Day01,
+    &day02::Day02,
+    &day03::Day03,
+];
+"#
+                .to_string()
+            ),
+            register(REGISTRY, 2)
+        );
+        assert!(register(REGISTRY, 12).unwrap().contains(
+            "pub mod day03;\npub mod day12;\n\n/// Every solved day, in order.\n\
+             pub const ALL: [&dyn AnySolution; 3] = [\n    &day01::Day01,\n    \
+             &day03::Day03,\n    &day12::Day12,\n];"
+        ));
+        assert_eq!(None, register(REGISTRY, 3));
+    }
+
+    #[test]
+    fn test_render() {
+        let module = render(8);
+
+        assert!(module.contains("pub struct Day08;"));
+        assert!(module.contains("const DAY: u8 = 8;"));
+        assert!(!module.contains("{{"));
+    }
+
+    #[test]
+    fn test_scaffold() {
+        let root = env::temp_dir().join(format!("aoc-scaffold-{}", process::id()));
+        let days = root.join("src").join("days");
+        let input_dir = root.join("files");
+        fs::create_dir_all(&days).unwrap();
+        fs::write(days.join("mod.rs"), REGISTRY).unwrap();
+
+        let scaffolded = scaffold(&root, &input_dir, 2).unwrap();
+
+        assert_eq!(days.join("day02.rs"), scaffolded.module);
+        assert_eq!(Some(input_dir.join("02.txt")), scaffolded.input);
+        assert_eq!(
+            render(2),
+            fs::read_to_string(days.join("day02.rs")).unwrap()
+        );
+        assert_eq!("", fs::read_to_string(input_dir.join("02.txt")).unwrap());
+        assert_eq!(
+            register(REGISTRY, 2).unwrap(),
+            fs::read_to_string(days.join("mod.rs")).unwrap()
+        );
+
+        fs::write(days.join("day02.rs"), "// solved").unwrap();
+        assert!(matches!(
+            scaffold(&root, &input_dir, 2),
+            Err(ScaffoldError::Exists(path)) if path == days.join("day02.rs")
+        ));
+        assert_eq!(
+            "// solved",
+            fs::read_to_string(days.join("day02.rs")).unwrap()
+        );
+
+        fs::write(input_dir.join("04.txt"), "input").unwrap();
+        assert_eq!(None, scaffold(&root, &input_dir, 4).unwrap().input);
+        assert_eq!(
+            "input",
+            fs::read_to_string(input_dir.join("04.txt")).unwrap()
+        );
+
+        assert!(matches!(
+            scaffold(&root, &input_dir, 1),
+            Err(ScaffoldError::Registered { day: 1, .. })
+        ));
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,134 @@
+use crate::ParseError;
+use std::{any::Any, error::Error, fmt::Display, str::FromStr};
+
+/// A solved puzzle day. The input is parsed once and shared by both parts.
+///
+/// A part returns `None` while it is not solved yet, or if it has no answer for the input,
+/// which [`Solution::unanswered`] then explains.
+pub trait Solution {
+    const DAY: u8;
+
+    type Input;
+    type Output: Display;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+
+    fn part1(input: &Self::Input) -> Option<Self::Output>;
+
+    fn part2(input: &Self::Input) -> Option<Self::Output>;
+
+    /// Why `part` returned `None` for `input` although it is solved, e.g. because the answer
+    /// doesn't fit in [`Solution::Output`]. Only asked after a `None`, and `None` itself
+    /// unless a day overrides it, meaning the part is not solved yet.
+    fn unanswered(_input: &Self::Input, _part: Part) -> Option<String> {
+        None
+    }
+}
+
+/// Object-safe view of a [`Solution`], so days can be stored in one table and run
+/// without knowing their input and output types.
+pub trait AnySolution: Send + Sync {
+    fn day(&self) -> u8;
+
+    /// Errors are tagged with the day.
+    fn parse(&self, input: &str) -> Result<Box<dyn Any + Send>, ParseError>;
+
+    /// `input` must come from [`AnySolution::parse`] of the same day. `Ok(None)` if the part
+    /// is not solved yet, an error if it has no answer for `input`.
+    fn solve(&self, input: &dyn Any, part: Part) -> Result<Option<String>, String>;
+}
+
+impl<S> AnySolution for S
+where
+    S: Solution + Send + Sync,
+    S::Input: Send + 'static,
+{
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn parse(&self, input: &str) -> Result<Box<dyn Any + Send>, ParseError> {
+        match S::parse(input) {
+            Ok(input) => Ok(Box::new(input)),
+            Err(err) => Err(err.with_day(S::DAY)),
+        }
+    }
+
+    fn solve(&self, input: &dyn Any, part: Part) -> Result<Option<String>, String> {
+        let input = input
+            .downcast_ref::<S::Input>()
+            .unwrap_or_else(|| panic!("Input of another day passed to day {:02}", S::DAY));
+
+        let answer = match part {
+            Part::One => S::part1(input),
+            Part::Two => S::part2(input),
+        };
+
+        match answer {
+            Some(answer) => Ok(Some(answer.to_string())),
+            None => S::unanswered(input, part).map_or(Ok(None), Err),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const BOTH: [Part; 2] = [Part::One, Part::Two];
+
+    pub const fn number(self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl FromStr for Part {
+    type Err = Box<dyn Error>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            _ => Err(format!("Invalid part: {s}. Expected 1 or 2").into()),
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.number().fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AnySolution, Part};
+    use crate::days::day01::Day01;
+
+    #[test]
+    fn test_any_solution() {
+        let input = AnySolution::parse(&Day01, "L68\nR18").unwrap();
+
+        assert_eq!(
+            Ok(Some("1".to_string())),
+            Day01.solve(input.as_ref(), Part::One)
+        );
+        assert_eq!(
+            Ok(Some("2".to_string())),
+            Day01.solve(input.as_ref(), Part::Two)
+        );
+    }
+
+    #[test]
+    fn test_parse_part() {
+        assert_eq!(Part::One, "1".parse().unwrap());
+        assert_eq!(Part::Two, "2".parse().unwrap());
+        assert!("3".parse::<Part>().is_err());
+    }
+}
@@ -0,0 +1,226 @@
+use crate::{
+    Solution,
+    days::{
+        day01::{self, Day01},
+        day02::{self, Day02},
+        day03::{self, Day03},
+    },
+    parse::ParseError,
+};
+use std::{
+    error::Error,
+    fmt::Display,
+    io::{self, BufRead},
+    str,
+};
+
+/// Days that solve both parts in a single pass over a reader, without holding the input in
+/// memory.
+pub const DAYS: [u8; 3] = [Day01::DAY, Day02::DAY, Day03::DAY];
+
+/// Both answers of `day`, read straight from `reader`, each like the result of
+/// [`AnySolution::solve`](crate::AnySolution::solve). `None` if the day can't be streamed.
+pub fn solve(day: u8, reader: impl BufRead) -> Option<Result<[Answer; 2], StreamError>> {
+    let answers = match day {
+        Day01::DAY => day01::solve_stream(reader).map(to_strings),
+        Day02::DAY => day02::solve_stream(reader).map(to_strings),
+        Day03::DAY => day03::solve_stream(reader)
+            .map(|answers| answers.map(|answer| answer.map(|answer| Some(answer.to_string())))),
+        _ => return None,
+    };
+
+    Some(answers)
+}
+
+/// `Ok(None)` if the part is not solved yet, an error if the input has no answer.
+pub type Answer = Result<Option<String>, String>;
+
+fn to_strings<T: ToString>(answers: [Option<T>; 2]) -> [Answer; 2] {
+    answers.map(|answer| Ok(answer.map(|answer| answer.to_string())))
+}
+
+/// Calls `f` with every line, without its line break, like [`str::lines`]. Only the current
+/// line is kept in memory. Errors of `f` are moved to the line they occurred on.
+pub fn for_each_line(
+    mut reader: impl BufRead,
+    mut f: impl FnMut(&str) -> Result<(), ParseError>,
+) -> Result<(), StreamError> {
+    let mut line = String::new();
+    let mut line_idx = 0;
+
+    loop {
+        line.clear();
+        if reader.read_line(&mut line)? == 0 {
+            return Ok(());
+        }
+
+        let content = line.strip_suffix('\n').unwrap_or(&line);
+        let content = content.strip_suffix('\r').unwrap_or(content);
+        f(content).map_err(|err| err.shift(line_idx, 0))?;
+
+        line_idx += 1;
+    }
+}
+
+/// Calls `f` with every non-empty token between bytes for which `separator` holds. Only the
+/// current token is kept in memory, however long the line. Errors of `f` are moved to the
+/// line and column of the token.
+pub fn for_each_token(
+    mut reader: impl BufRead,
+    separator: impl Fn(u8) -> bool,
+    mut f: impl FnMut(&str) -> Result<(), ParseError>,
+) -> Result<(), StreamError> {
+    let mut token = Vec::new();
+    // 0-based line and column of the next byte, and of the start of the token.
+    let (mut line, mut column) = (0, 0);
+    let mut start = (0, 0);
+
+    loop {
+        let buf = reader.fill_buf()?;
+        if buf.is_empty() {
+            return emit(&mut token, start, &mut f);
+        }
+
+        for &byte in buf {
+            if separator(byte) {
+                emit(&mut token, start, &mut f)?;
+            } else {
+                if token.is_empty() {
+                    start = (line, column);
+                }
+                token.push(byte);
+            }
+
+            if byte == b'\n' {
+                (line, column) = (line + 1, 0);
+            } else if byte & 0xc0 != 0x80 {
+                // Columns count characters, not the continuation bytes of UTF-8.
+                column += 1;
+            }
+        }
+
+        let len = buf.len();
+        reader.consume(len);
+    }
+}
+
+fn emit(
+    token: &mut Vec<u8>,
+    (line, column): (usize, usize),
+    f: &mut impl FnMut(&str) -> Result<(), ParseError>,
+) -> Result<(), StreamError> {
+    if token.is_empty() {
+        return Ok(());
+    }
+
+    let text =
+        str::from_utf8(token).map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+    f(text).map_err(|err| err.shift(line, column))?;
+    token.clear();
+
+    Ok(())
+}
+
+#[derive(Debug)]
+pub enum StreamError {
+    Io(io::Error),
+    Parse(ParseError),
+}
+
+impl Display for StreamError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StreamError::Io(err) => write!(f, "failed to read the input: {err}"),
+            StreamError::Parse(err) => err.fmt(f),
+        }
+    }
+}
+
+impl Error for StreamError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            StreamError::Io(err) => Some(err),
+            StreamError::Parse(err) => Some(err),
+        }
+    }
+}
+
+impl From<io::Error> for StreamError {
+    fn from(err: io::Error) -> Self {
+        StreamError::Io(err)
+    }
+}
+
+impl From<ParseError> for StreamError {
+    fn from(err: ParseError) -> Self {
+        StreamError::Parse(err)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{StreamError, for_each_line, for_each_token, solve};
+    use crate::parse::{ParseError, ParseErrorKind, parse_number};
+    use std::io::BufReader;
+
+    #[test]
+    fn test_for_each_line() {
+        let mut lines = Vec::new();
+        for_each_line("a\r\nb\n\nc".as_bytes(), |line| {
+            lines.push(line.to_string());
+            Ok(())
+        })
+        .unwrap();
+
+        assert_eq!(vec!["a", "b", "", "c"], lines);
+    }
+
+    #[test]
+    fn test_for_each_token() {
+        // A tiny buffer splits tokens and characters across reads.
+        let reader = BufReader::with_capacity(2, "12,é3,,\n 45".as_bytes());
+        let mut tokens = Vec::new();
+        for_each_token(
+            reader,
+            |byte| byte == b',' || byte.is_ascii_whitespace(),
+            |token| {
+                tokens.push(token.to_string());
+                Ok(())
+            },
+        )
+        .unwrap();
+
+        assert_eq!(vec!["12", "é3", "45"], tokens);
+
+        let err = for_each_token(
+            "1,é,\n 2x".as_bytes(),
+            |byte| byte == b',' || byte.is_ascii_whitespace(),
+            |token| parse_number::<u64>(token).map(|_| ()),
+        );
+        assert!(matches!(
+            err,
+            Err(StreamError::Parse(err)) if err == ParseError::new(
+                1,
+                3,
+                ParseErrorKind::UnexpectedChar {
+                    expected: "digit",
+                    found: 'é'
+                }
+            )
+        ));
+
+        assert!(matches!(
+            for_each_token(&[b'1', b',', 0xff][..], |byte| byte == b',', |_| Ok(())),
+            Err(StreamError::Io(_))
+        ));
+    }
+
+    #[test]
+    fn test_solve() {
+        assert!(solve(4, "".as_bytes()).is_none());
+        assert_eq!(
+            [Ok(Some("1".to_string())), Ok(Some("1".to_string()))],
+            solve(1, "R50\n".as_bytes()).unwrap().unwrap()
+        );
+    }
+}
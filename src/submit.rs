@@ -0,0 +1,604 @@
+use crate::{
+    Part,
+    config::YEAR,
+    http::{Client, HttpError},
+    parse::{ParseError, ParseErrorKind, parse_number, tokens},
+};
+use std::{fmt::Display, str::FromStr, time::Duration};
+
+/// What the site said about a submitted answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Correct,
+    Wrong {
+        hint: Option<Hint>,
+        /// How long the site blocks further answers.
+        wait: Option<Duration>,
+    },
+    RateLimited {
+        wait: Duration,
+    },
+    /// The part was solved before, e.g. through the browser.
+    AlreadySolved,
+    /// Text of a response none of the above match.
+    Unknown(String),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Hint {
+    TooHigh,
+    TooLow,
+}
+
+/// Posts `answer` as the answer to `part` of `day`.
+pub fn submit(client: &Client, day: u8, part: Part, answer: &str) -> Result<Outcome, HttpError> {
+    let level = part.to_string();
+    let response = client.post_form(
+        &format!("/{YEAR}/day/{day}/answer"),
+        &[("level", &level), ("answer", answer)],
+    )?;
+
+    if !(200..300).contains(&response.status) {
+        return Err(HttpError::Status {
+            status: response.status,
+            message: response.body.lines().next().unwrap_or_default().to_string(),
+        });
+    }
+
+    Ok(parse_outcome(&response.body))
+}
+
+/// Reads the outcome from the text of the response page.
+pub fn parse_outcome(html: &str) -> Outcome {
+    let text = article_text(html);
+
+    if text.contains("That's the right answer") {
+        Outcome::Correct
+    } else if text.contains("That's not the right answer") {
+        let hint = if text.contains("too high") {
+            Some(Hint::TooHigh)
+        } else if text.contains("too low") {
+            Some(Hint::TooLow)
+        } else {
+            None
+        };
+
+        Outcome::Wrong {
+            hint,
+            wait: parse_wrong_wait(&text),
+        }
+    } else if text.contains("You gave an answer too recently") {
+        Outcome::RateLimited {
+            // The site always says how long, but waiting a minute is safe if it doesn't.
+            wait: parse_left_to_wait(&text).unwrap_or(Duration::from_secs(60)),
+        }
+    } else if text.contains("Did you already complete it") {
+        Outcome::AlreadySolved
+    } else {
+        Outcome::Unknown(text)
+    }
+}
+
+/// Text of the `<article>` with the message, tags removed and whitespace collapsed.
+fn article_text(html: &str) -> String {
+    // Inside the opening tag right after the split.
+    let (article, mut in_tag) = match html
+        .split_once("<article")
+        .and_then(|(_, rest)| rest.split_once("</article>"))
+    {
+        Some((article, _)) => (article, true),
+        None => (html, false),
+    };
+
+    let mut text = String::new();
+    for ch in article.chars() {
+        match ch {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            ch if !in_tag => text.push(ch),
+            _ => {}
+        }
+    }
+
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// "You have 1m 5s left to wait."
+fn parse_left_to_wait(text: &str) -> Option<Duration> {
+    let (_, rest) = text.split_once("You have ")?;
+    let (amount, _) = rest.split_once(" left to wait")?;
+
+    amount
+        .split_whitespace()
+        .try_fold(Duration::ZERO, |total, token| {
+            let (number, unit) = token.split_at(token.find(|ch: char| !ch.is_ascii_digit())?);
+            let number: u64 = number.parse().ok()?;
+            let seconds = match unit {
+                "h" => 3600,
+                "m" => 60,
+                "s" => 1,
+                _ => return None,
+            };
+
+            Some(total + Duration::from_secs(number * seconds))
+        })
+}
+
+/// "please wait one minute before trying again", or "5 minutes" after repeated mistakes.
+fn parse_wrong_wait(text: &str) -> Option<Duration> {
+    let lower = text.to_lowercase();
+    let (_, rest) = lower.split_once("please wait ")?;
+    let mut words = rest.split_whitespace();
+
+    let minutes = match words.next()? {
+        "one" => 1,
+        number => number.parse().ok()?,
+    };
+    words
+        .next()?
+        .starts_with("minute")
+        .then(|| Duration::from_secs(minutes * 60))
+}
+
+/// The locally known result of an answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    Wrong(Option<Hint>),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Submission {
+    day: u8,
+    part: Part,
+    answer: String,
+    verdict: Verdict,
+}
+
+/// Every answer submitted so far and until when the site blocks new ones, one per line:
+///
+/// ```text
+/// 07 1 correct 1537
+/// 07 2 too-high 5000
+/// wait-until 1760774400
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Submissions {
+    submissions: Vec<Submission>,
+    /// Unix time in seconds.
+    wait_until: Option<u64>,
+}
+
+/// Why an answer is not submitted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Refusal {
+    AlreadySolved {
+        answer: String,
+    },
+    KnownWrong {
+        hint: Option<Hint>,
+    },
+    /// A wrong answer that was too high is at most `bound`, or too low at least it.
+    OutOfBounds {
+        hint: Hint,
+        bound: String,
+    },
+    Throttled {
+        wait: Duration,
+    },
+}
+
+impl Submissions {
+    /// Refuses answers that are known to be wrong, or if the site still blocks answers
+    /// at `now` in Unix seconds.
+    pub fn check(&self, day: u8, part: Part, answer: &str, now: u64) -> Option<Refusal> {
+        let submissions = self
+            .submissions
+            .iter()
+            .filter(|s| s.day == day && s.part == part);
+
+        for submission in submissions {
+            match submission.verdict {
+                Verdict::Correct => {
+                    return Some(Refusal::AlreadySolved {
+                        answer: submission.answer.clone(),
+                    });
+                }
+                Verdict::Wrong(hint) if submission.answer == answer => {
+                    return Some(Refusal::KnownWrong { hint });
+                }
+                Verdict::Wrong(Some(hint)) if is_beyond(answer, &submission.answer, hint) => {
+                    return Some(Refusal::OutOfBounds {
+                        hint,
+                        bound: submission.answer.clone(),
+                    });
+                }
+                Verdict::Wrong(_) => {}
+            }
+        }
+
+        match self.wait_until {
+            Some(until) if until > now => Some(Refusal::Throttled {
+                wait: Duration::from_secs(until - now),
+            }),
+            _ => None,
+        }
+    }
+
+    /// Remembers what the site said about `answer`, submitted at `now` in Unix seconds.
+    pub fn record(&mut self, day: u8, part: Part, answer: &str, outcome: &Outcome, now: u64) {
+        let verdict = match outcome {
+            Outcome::Correct => Some(Verdict::Correct),
+            Outcome::Wrong { hint, .. } => Some(Verdict::Wrong(*hint)),
+            Outcome::RateLimited { .. } | Outcome::AlreadySolved | Outcome::Unknown(_) => None,
+        };
+        if let Some(verdict) = verdict {
+            self.submissions.push(Submission {
+                day,
+                part,
+                answer: answer.to_string(),
+                verdict,
+            });
+        }
+
+        let wait = match outcome {
+            Outcome::Wrong { wait, .. } => *wait,
+            Outcome::RateLimited { wait } => Some(*wait),
+            _ => None,
+        };
+        self.wait_until = wait.map(|wait| now + wait.as_secs());
+    }
+}
+
+/// Compares integer answers, anything else is never out of bounds.
+fn is_beyond(answer: &str, bound: &str, hint: Hint) -> bool {
+    let (Ok(answer), Ok(bound)) = (answer.parse::<i128>(), bound.parse::<i128>()) else {
+        return false;
+    };
+
+    match hint {
+        Hint::TooHigh => answer >= bound,
+        Hint::TooLow => answer <= bound,
+    }
+}
+
+impl FromStr for Submissions {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut submissions = Submissions::default();
+
+        for (i, line) in s.lines().enumerate() {
+            let fields: Vec<(usize, &str)> = tokens(line).collect();
+            let field = |n: usize, expected| {
+                fields.get(n).copied().ok_or(
+                    ParseError::new(
+                        1,
+                        line.len() + 1,
+                        ParseErrorKind::UnexpectedEnd { expected },
+                    )
+                    .shift(i, 0),
+                )
+            };
+            match fields.first() {
+                None => continue,
+                Some(&(_, "wait-until")) => {
+                    let (column, time) = field(1, "time")?;
+                    submissions.wait_until =
+                        Some(parse_number(time).map_err(|err| err.shift(i, column))?);
+                    continue;
+                }
+                Some(_) => {}
+            }
+
+            let (column, day) = field(0, "day")?;
+            let day = parse_number(day).map_err(|err| err.shift(i, column))?;
+            let (column, part) = field(1, "part")?;
+            let part = match part {
+                "1" => Part::One,
+                "2" => Part::Two,
+                _ => return Err(unexpected(i, column, "part 1 or 2", part)),
+            };
+            let (column, verdict) = field(2, "verdict")?;
+            let verdict = match verdict {
+                "correct" => Verdict::Correct,
+                "wrong" => Verdict::Wrong(None),
+                "too-high" => Verdict::Wrong(Some(Hint::TooHigh)),
+                "too-low" => Verdict::Wrong(Some(Hint::TooLow)),
+                _ => {
+                    return Err(unexpected(
+                        i,
+                        column,
+                        "correct, wrong, too-high or too-low",
+                        verdict,
+                    ));
+                }
+            };
+            let (_, answer) = field(3, "answer")?;
+
+            submissions.submissions.push(Submission {
+                day,
+                part,
+                answer: answer.to_string(),
+                verdict,
+            });
+        }
+
+        Ok(submissions)
+    }
+}
+
+fn unexpected(line: usize, column: usize, expected: &'static str, found: &str) -> ParseError {
+    ParseError::new(
+        line + 1,
+        column + 1,
+        ParseErrorKind::UnexpectedChar {
+            expected,
+            found: found.chars().next().unwrap_or(' '),
+        },
+    )
+}
+
+impl Display for Submissions {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for submission in &self.submissions {
+            let verdict = match submission.verdict {
+                Verdict::Correct => "correct",
+                Verdict::Wrong(None) => "wrong",
+                Verdict::Wrong(Some(Hint::TooHigh)) => "too-high",
+                Verdict::Wrong(Some(Hint::TooLow)) => "too-low",
+            };
+
+            writeln!(
+                f,
+                "{:02} {} {verdict} {}",
+                submission.day, submission.part, submission.answer
+            )?;
+        }
+
+        if let Some(until) = self.wait_until {
+            writeln!(f, "wait-until {until}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Display for Hint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Hint::TooHigh => f.write_str("too high"),
+            Hint::TooLow => f.write_str("too low"),
+        }
+    }
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Outcome::Correct => f.write_str("correct"),
+            Outcome::Wrong { hint, wait } => {
+                f.write_str("wrong")?;
+                if let Some(hint) = hint {
+                    write!(f, ", {hint}")?;
+                }
+                if let Some(wait) = wait {
+                    write!(f, ". Wait {}s before the next answer", wait.as_secs())?;
+                }
+                Ok(())
+            }
+            Outcome::RateLimited { wait } => {
+                write!(f, "answered too recently, wait {}s", wait.as_secs())
+            }
+            Outcome::AlreadySolved => f.write_str("already solved"),
+            Outcome::Unknown(text) => write!(f, "unrecognised response: {text}"),
+        }
+    }
+}
+
+impl Display for Refusal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Refusal::AlreadySolved { answer } => write!(f, "already solved with {answer}"),
+            Refusal::KnownWrong { hint: None } => f.write_str("already submitted, it is wrong"),
+            Refusal::KnownWrong { hint: Some(hint) } => {
+                write!(f, "already submitted, it is {hint}")
+            }
+            Refusal::OutOfBounds { hint, bound } => write!(f, "{bound} was already {hint}"),
+            Refusal::Throttled { wait } => {
+                write!(f, "the site blocks answers for {}s more", wait.as_secs())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Hint, Outcome, Refusal, Submissions, parse_outcome, submit};
+    use crate::{
+        Part,
+        http::Client,
+        parse::{ParseError, ParseErrorKind},
+        test_support::MockServer,
+    };
+    use std::time::Duration;
+
+    fn page(message: &str) -> String {
+        format!(
+            "<!DOCTYPE html>\n<html><body><main>\n<article><p>{message}</p></article>\n</main></body></html>"
+        )
+    }
+
+    #[test]
+    fn test_parse_outcome() {
+        assert_eq!(
+            Outcome::Correct,
+            parse_outcome(&page(
+                "That's the right answer!  You are <span class=\"day-success\">one gold star</span> closer."
+            ))
+        );
+        assert_eq!(
+            Outcome::Wrong {
+                hint: Some(Hint::TooHigh),
+                wait: Some(Duration::from_secs(60))
+            },
+            parse_outcome(&page(
+                "That's not the right answer; your answer is too high.  If you're stuck, \
+                 make sure you're using the full input data.  Please wait one minute before \
+                 trying again. <a href=\"/2025/day/7\">[Return to Day 7]</a>"
+            ))
+        );
+        assert_eq!(
+            Outcome::Wrong {
+                hint: None,
+                wait: Some(Duration::from_secs(300))
+            },
+            parse_outcome(&page(
+                "That's not the right answer.  Because you have guessed incorrectly 4 times \
+                 on this puzzle, please wait 5 minutes before trying again."
+            ))
+        );
+        assert_eq!(
+            Outcome::RateLimited {
+                wait: Duration::from_secs(65)
+            },
+            parse_outcome(&page(
+                "You gave an answer too recently; you have to wait after submitting an answer \
+                 before trying again.  You have 1m 5s left to wait."
+            ))
+        );
+        assert_eq!(
+            Outcome::AlreadySolved,
+            parse_outcome(&page(
+                "You don't seem to be solving the right level.  Did you already complete it?"
+            ))
+        );
+        assert_eq!(
+            Outcome::Unknown("Something else".to_string()),
+            parse_outcome(&page("Something <em>else</em>"))
+        );
+    }
+
+    #[test]
+    fn test_check() {
+        let mut submissions = Submissions::default();
+        let wrong = |hint| Outcome::Wrong {
+            hint,
+            wait: Some(Duration::from_secs(60)),
+        };
+
+        submissions.record(7, Part::Two, "5000", &wrong(Some(Hint::TooHigh)), 1000);
+        assert_eq!(
+            Some(Refusal::Throttled {
+                wait: Duration::from_secs(30)
+            }),
+            submissions.check(7, Part::Two, "4000", 1030)
+        );
+
+        submissions.record(7, Part::Two, "10", &wrong(Some(Hint::TooLow)), 1100);
+        submissions.record(7, Part::Two, "42", &wrong(None), 1200);
+        let check = |answer| submissions.check(7, Part::Two, answer, 2000);
+
+        assert_eq!(
+            Some(Refusal::OutOfBounds {
+                hint: Hint::TooHigh,
+                bound: "5000".to_string()
+            }),
+            check("6000")
+        );
+        assert_eq!(
+            Some(Refusal::OutOfBounds {
+                hint: Hint::TooLow,
+                bound: "10".to_string()
+            }),
+            check("9")
+        );
+        assert_eq!(Some(Refusal::KnownWrong { hint: None }), check("42"));
+        assert_eq!(None, check("40"));
+        assert_eq!(None, submissions.check(7, Part::One, "6000", 2000));
+
+        submissions.record(7, Part::Two, "40", &Outcome::Correct, 2000);
+        assert_eq!(
+            Some(Refusal::AlreadySolved {
+                answer: "40".to_string()
+            }),
+            submissions.check(7, Part::Two, "41", 2000)
+        );
+    }
+
+    #[test]
+    fn test_display_roundtrip() {
+        let mut submissions = Submissions::default();
+        submissions.record(
+            7,
+            Part::Two,
+            "5000",
+            &Outcome::Wrong {
+                hint: Some(Hint::TooHigh),
+                wait: None,
+            },
+            0,
+        );
+        submissions.record(7, Part::One, "1537", &Outcome::Correct, 0);
+        submissions.record(
+            8,
+            Part::One,
+            "1",
+            &Outcome::RateLimited {
+                wait: Duration::from_secs(30),
+            },
+            100,
+        );
+
+        let text = "07 2 too-high 5000\n07 1 correct 1537\nwait-until 130\n";
+        assert_eq!(text, submissions.to_string());
+        assert_eq!(submissions, text.parse().unwrap());
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            Err(ParseError::new(
+                2,
+                6,
+                ParseErrorKind::UnexpectedChar {
+                    expected: "correct, wrong, too-high or too-low",
+                    found: 'r'
+                }
+            )),
+            "07 1 correct 1537\n07 2 right 40".parse::<Submissions>()
+        );
+        assert_eq!(
+            Err(ParseError::new(
+                1,
+                13,
+                ParseErrorKind::UnexpectedEnd { expected: "answer" }
+            )),
+            "07 2 too-low".parse::<Submissions>()
+        );
+    }
+
+    #[test]
+    fn test_submit() {
+        let server = MockServer::start(vec![(200, page("That's the right answer!"))]);
+        let client = Client::new(server.url(), "secret");
+
+        assert_eq!(
+            Outcome::Correct,
+            submit(&client, 7, Part::Two, "40").unwrap()
+        );
+
+        let request = &server.requests()[0];
+        assert!(request.starts_with("POST /2025/day/7/answer HTTP/1.1\r\n"));
+        assert!(request.contains("Cookie: session=secret\r\n"));
+        assert!(request.ends_with("\r\n\r\nlevel=2&answer=40"));
+
+        let server = MockServer::start(vec![(400, "Bad Request\nmore".to_string())]);
+        let client = Client::new(server.url(), "secret");
+
+        assert_eq!(
+            "unexpected response 400: Bad Request",
+            submit(&client, 7, Part::Two, "40").unwrap_err().to_string()
+        );
+    }
+}
@@ -0,0 +1,177 @@
+use crate::{Part, Solution};
+use std::{
+    fmt::Debug,
+    io::{BufRead, BufReader, Read, Write},
+    net::TcpListener,
+    sync::{Arc, Mutex},
+    thread,
+};
+
+/// Generates `test_part1`, `test_part2` and, if there are extra examples, `test_extra_examples`
+/// for the puzzle examples of a day.
+///
+/// The main example must give both parts. Write `unsolved` for a part that is not solved yet,
+/// which checks that the solution still returns `None`, so solving it fails the test until the
+/// expected answer is filled in. Extra examples give any of the parts.
+///
+/// ```ignore
+/// examples! {
+///     Day01 {
+///         TEST => part1: 3, part2: 6;
+///         "R1000" => part2: 10;
+///     }
+/// }
+/// ```
+macro_rules! examples {
+    (
+        $solution:ty {
+            $input:expr => part1: $part1:tt, part2: $part2:tt;
+            $($extra:expr => $($part:ident: $expected:expr),+;)*
+        }
+    ) => {
+        #[test]
+        fn test_part1() {
+            $crate::test_support::check_example::<$solution>(
+                $input,
+                $crate::Part::One,
+                $crate::test_support::examples!(@expected $part1),
+            );
+        }
+
+        #[test]
+        fn test_part2() {
+            $crate::test_support::check_example::<$solution>(
+                $input,
+                $crate::Part::Two,
+                $crate::test_support::examples!(@expected $part2),
+            );
+        }
+
+        $crate::test_support::examples!(@extra $solution; $($extra => $($part: $expected),+;)*);
+    };
+    (@extra $solution:ty;) => {};
+    (@extra $solution:ty; $($extra:expr => $($part:ident: $expected:expr),+;)+) => {
+        #[test]
+        fn test_extra_examples() {
+            $($(
+                $crate::test_support::check_example::<$solution>(
+                    $extra,
+                    $crate::test_support::examples!(@part $part),
+                    Some($expected),
+                );
+            )+)+
+        }
+    };
+    (@expected unsolved) => {
+        None
+    };
+    (@expected $expected:expr) => {
+        Some($expected)
+    };
+    (@part part1) => {
+        $crate::Part::One
+    };
+    (@part part2) => {
+        $crate::Part::Two
+    };
+}
+
+pub(crate) use examples;
+
+/// Parses `input` and compares the answer to `part` with `expected`, `None` meaning unsolved.
+pub fn check_example<S: Solution>(input: &str, part: Part, expected: Option<S::Output>)
+where
+    S::Output: PartialEq + Debug,
+{
+    let parsed = S::parse(input).unwrap_or_else(|err| panic!("example does not parse: {err}"));
+    let answer = match part {
+        Part::One => S::part1(&parsed),
+        Part::Two => S::part2(&parsed),
+    };
+
+    assert_eq!(
+        expected,
+        answer,
+        "day {:02} part {part} of example:\n{input}",
+        S::DAY
+    );
+}
+
+/// A local stand-in for the puzzle site. Answers each connection with the next canned
+/// `(status, body)` and records the raw requests.
+pub struct MockServer {
+    url: String,
+    requests: Arc<Mutex<Vec<String>>>,
+}
+
+impl MockServer {
+    pub fn start(responses: Vec<(u16, String)>) -> MockServer {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let requests = Arc::new(Mutex::new(Vec::new()));
+
+        let recorded = Arc::clone(&requests);
+        thread::spawn(move || {
+            for (status, body) in responses {
+                let Ok((stream, _)) = listener.accept() else {
+                    return;
+                };
+                let mut reader = BufReader::new(stream);
+
+                let mut request = String::new();
+                let mut length = 0;
+                loop {
+                    let mut line = String::new();
+                    if reader.read_line(&mut line).unwrap() == 0 || line == "\r\n" {
+                        request += &line;
+                        break;
+                    }
+                    if let Some((name, value)) = line.split_once(':')
+                        && name.eq_ignore_ascii_case("content-length")
+                    {
+                        length = value.trim().parse().unwrap();
+                    }
+                    request += &line;
+                }
+                let mut content = vec![0; length];
+                reader.read_exact(&mut content).unwrap();
+                request += &String::from_utf8(content).unwrap();
+                recorded.lock().unwrap().push(request);
+
+                let response = format!(
+                    "HTTP/1.1 {status} Mock\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                );
+                reader.get_mut().write_all(response.as_bytes()).unwrap();
+            }
+        });
+
+        MockServer { url, requests }
+    }
+
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// Requests received so far.
+    pub fn requests(&self) -> Vec<String> {
+        self.requests.lock().unwrap().clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::check_example;
+    use crate::{Part, days::day01::Day01};
+
+    #[test]
+    fn test_check_example() {
+        check_example::<Day01>("L50", Part::One, Some(1));
+    }
+
+    #[test]
+    #[should_panic(expected = "day 01 part 2 of example")]
+    fn test_check_example_mismatch() {
+        check_example::<Day01>("L50", Part::Two, Some(2));
+    }
+}
@@ -0,0 +1,288 @@
+use crate::{
+    ParseError, Solution,
+    days::{
+        day04::{self, Day04},
+        day07::{self, Day07},
+    },
+    grid::Grid,
+};
+use std::{
+    fs::File,
+    io::{self, IsTerminal, Read, Write},
+    process::{Command, Stdio},
+    thread,
+    time::{Duration, Instant},
+};
+
+/// What a cell of a frame shows, independent of how it is drawn.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Tile {
+    Empty,
+    Paper,
+    /// Paper removed `n` rounds ago, fading out.
+    Fading(u8),
+    Start,
+    Splitter,
+    /// A splitter a beam has hit.
+    SplitterHit,
+    Beam,
+    /// The row the beams reached in this frame.
+    BeamFront,
+    /// A mirror, directional splitter or absorber, drawn as itself.
+    Optic(char),
+}
+
+/// One step of a simulation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame {
+    pub caption: String,
+    pub tiles: Grid<Tile>,
+}
+
+/// Days with a visualization.
+pub const DAYS: [u8; 2] = [Day04::DAY, Day07::DAY];
+
+/// The frames of `day`, `None` if it has no visualization.
+pub fn frames(day: u8, input: &str) -> Option<Result<Vec<Frame>, ParseError>> {
+    let frames = match day {
+        Day04::DAY => Day04::parse(input).map(|grid| day04::removal_frames(&grid)),
+        Day07::DAY => Day07::parse(input).map(|manifold| day07::beam_frames(&manifold)),
+        _ => return None,
+    };
+
+    Some(frames.map_err(|err| err.with_day(day)))
+}
+
+impl Tile {
+    pub fn glyph(self) -> char {
+        match self {
+            Tile::Empty => '.',
+            Tile::Paper => '@',
+            Tile::Fading(0 | 1) => '@',
+            Tile::Fading(_) => ':',
+            Tile::Start => 'S',
+            Tile::Splitter | Tile::SplitterHit => '^',
+            Tile::Beam | Tile::BeamFront => ':',
+            Tile::Optic(ch) => ch,
+        }
+    }
+
+    /// SGR parameters of the ANSI colour.
+    fn ansi(self) -> &'static str {
+        match self {
+            Tile::Empty => "90",
+            Tile::Paper => "37",
+            Tile::Fading(0) => "1;91",
+            Tile::Fading(1) => "31",
+            Tile::Fading(_) => "2;31",
+            Tile::Start => "1;32",
+            Tile::Splitter | Tile::Optic(_) => "36",
+            Tile::SplitterHit => "1;96",
+            Tile::Beam => "33",
+            Tile::BeamFront => "1;93",
+        }
+    }
+}
+
+/// The frame in ANSI colours, one line per row, switching colours only where they change.
+pub fn render_ansi(frame: &Frame) -> String {
+    let mut out = String::new();
+
+    for row in frame.tiles.rows() {
+        let mut current = None;
+
+        for &tile in row {
+            if current != Some(tile.ansi()) {
+                out += &format!("\x1b[0;{}m", tile.ansi());
+                current = Some(tile.ansi());
+            }
+            out.push(tile.glyph());
+        }
+
+        out += "\x1b[0m\n";
+    }
+
+    out += &frame.caption;
+    out += "\x1b[K\n";
+
+    out
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PlayOptions {
+    pub delay: Duration,
+    /// Start paused, waiting for steps.
+    pub paused: bool,
+}
+
+impl Default for PlayOptions {
+    fn default() -> Self {
+        PlayOptions {
+            delay: Duration::from_millis(100),
+            paused: false,
+        }
+    }
+}
+
+const HELP: &str = "space pause/resume, n step, +/- speed, q quit";
+
+/// Draws the frames over each other on the terminal. Keys control the playback if stdin is a
+/// terminal.
+pub fn play(frames: &[Frame], options: PlayOptions) -> io::Result<()> {
+    let mut keys = Keys::open();
+    let mut out = io::stdout().lock();
+    let mut delay = options.delay;
+    let mut paused = options.paused && keys.is_some();
+
+    // Clear the screen and hide the cursor.
+    write!(out, "\x1b[2J\x1b[?25l")?;
+
+    let result = (|| {
+        for (i, frame) in frames.iter().enumerate() {
+            write!(out, "\x1b[H{}", render_ansi(frame))?;
+            let state = if paused { "paused" } else { "playing" };
+            match &keys {
+                Some(_) => write!(
+                    out,
+                    "frame {}/{} {state} ({HELP})\x1b[K",
+                    i + 1,
+                    frames.len()
+                )?,
+                None => write!(out, "frame {}/{}\x1b[K", i + 1, frames.len())?,
+            }
+            out.flush()?;
+
+            if i + 1 == frames.len() {
+                break;
+            }
+
+            let Some(keys) = &mut keys else {
+                thread::sleep(delay);
+                continue;
+            };
+
+            let mut next = Instant::now() + delay;
+            loop {
+                match keys.poll() {
+                    Some(b' ') => {
+                        paused = !paused;
+                        next = Instant::now() + delay;
+                    }
+                    Some(b'n' | b'.') if paused => break,
+                    Some(b'+') => delay /= 2,
+                    Some(b'-') => delay *= 2,
+                    // q, Esc or Ctrl-C
+                    Some(b'q' | 0x1b | 0x03) => return Ok(()),
+                    _ => {}
+                }
+
+                if !paused && Instant::now() >= next {
+                    break;
+                }
+                thread::sleep(Duration::from_millis(10));
+            }
+        }
+
+        Ok(())
+    })();
+
+    // Show the cursor again.
+    writeln!(out, "\x1b[?25h")?;
+    result
+}
+
+/// Single key presses from the terminal, read without waiting for Enter. Restores the
+/// terminal when dropped.
+struct Keys {
+    tty: File,
+    saved: String,
+}
+
+impl Keys {
+    /// `None` if stdin is not a terminal or `stty` is not available.
+    fn open() -> Option<Keys> {
+        if !io::stdin().is_terminal() {
+            return None;
+        }
+
+        // Built before the terminal changes, so that dropping it restores the terminal from
+        // then on, also if changing it fails.
+        let keys = Keys {
+            tty: File::open("/dev/tty").ok()?,
+            saved: stty(&["-g"])?.trim().to_string(),
+        };
+
+        // Non-canonical without echo, reads return at once, empty if no key was pressed.
+        // Ctrl-C arrives as a key too, so that quitting always restores the terminal.
+        stty(&["-icanon", "-echo", "-isig", "min", "0", "time", "0"])?;
+
+        Some(keys)
+    }
+
+    fn poll(&mut self) -> Option<u8> {
+        let mut key = [0];
+        match self.tty.read(&mut key) {
+            Ok(1) => Some(key[0]),
+            _ => None,
+        }
+    }
+}
+
+impl Drop for Keys {
+    fn drop(&mut self) {
+        stty(&[&self.saved]);
+    }
+}
+
+fn stty(args: &[&str]) -> Option<String> {
+    let output = Command::new("stty")
+        .args(args)
+        .stdin(File::open("/dev/tty").ok()?)
+        .stderr(Stdio::null())
+        .output()
+        .ok()?;
+
+    output
+        .status
+        .success()
+        .then(|| String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Frame, Tile, frames, render_ansi};
+    use crate::grid::Grid;
+
+    #[test]
+    fn test_render_ansi() {
+        let frame = Frame {
+            caption: "row 1".to_string(),
+            tiles: Grid::new(
+                3,
+                2,
+                vec![
+                    Tile::Empty,
+                    Tile::Start,
+                    Tile::Empty,
+                    Tile::Beam,
+                    Tile::Beam,
+                    Tile::SplitterHit,
+                ],
+            ),
+        };
+
+        assert_eq!(
+            "\x1b[0;90m.\x1b[0;1;32mS\x1b[0;90m.\x1b[0m\n\
+             \x1b[0;33m::\x1b[0;1;96m^\x1b[0m\n\
+             row 1\x1b[K\n",
+            render_ansi(&frame)
+        );
+    }
+
+    #[test]
+    fn test_frames() {
+        assert!(frames(1, "L1").is_none());
+        assert!(frames(4, "x").unwrap().is_err());
+        assert_eq!(2, frames(7, "S\n.").unwrap().unwrap().len());
+    }
+}
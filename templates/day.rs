@@ -0,0 +1,36 @@
+use crate::{ParseError, Solution};
+
+pub struct Day{{day}};
+
+impl Solution for Day{{day}} {
+    const DAY: u8 = {{number}};
+
+    type Input = Vec<String>;
+    type Output = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(input.lines().map(str::to_string).collect())
+    }
+
+    fn part1(_input: &Self::Input) -> Option<u64> {
+        None
+    }
+
+    fn part2(_input: &Self::Input) -> Option<u64> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day{{day}};
+    use crate::test_support::examples;
+
+    const TEST: &str = r#""#;
+
+    examples! {
+        Day{{day}} {
+            TEST => part1: unsolved, part2: unsolved;
+        }
+    }
+}